@@ -0,0 +1,188 @@
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::{collections::HashSet, ops::Range};
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+use utils::{
+    geom::{Direction, Point},
+    grid::Grid,
+};
+
+/// Where the guard is and which way they are facing
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Guard {
+    pub location: Point,
+    pub direction: Direction,
+}
+
+/// Every step of the guard's patrol until they leave the map, starting with
+/// where they stand. Turning on the spot counts as a step.
+#[derive(Debug)]
+pub struct Walk<'a> {
+    guard: Guard,
+    obstacles: &'a HashSet<Point>,
+    rows: &'a Range<isize>,
+    columns: &'a Range<isize>,
+    first: bool,
+}
+
+impl Iterator for Walk<'_> {
+    type Item = Guard;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first {
+            self.first = false;
+            return Some(self.guard);
+        }
+        let next = self.guard.location.step(self.guard.direction);
+        if self.obstacles.contains(&next) {
+            self.guard.direction = self.guard.direction.rotate_right();
+            Some(self.guard)
+        } else if self.rows.contains(&next.row) && self.columns.contains(&next.col) {
+            self.guard.location = next;
+            Some(self.guard)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct InputData {
+    obstacles: HashSet<Point>,
+    start: Point,
+    rows: Range<isize>,
+    columns: Range<isize>,
+}
+
+impl InputData {
+    /// The guard's patrol from the start of the map
+    #[must_use]
+    pub fn walk(&self) -> Walk<'_> {
+        Walk {
+            guard: Guard {
+                location: self.start,
+                direction: Direction::North,
+            },
+            obstacles: &self.obstacles,
+            rows: &self.rows,
+            columns: &self.columns,
+            first: true,
+        }
+    }
+
+    /// The same map with an extra obstacle at `p`
+    #[must_use]
+    pub fn alternate(&self, p: Point) -> Self {
+        let mut alt = self.clone();
+        alt.obstacles.insert(p);
+        alt
+    }
+}
+
+fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::error::{ParseError, VerboseError};
+
+    let (remaining, grid) = Grid::parse(input)?;
+    let start = grid
+        .find(&b'^')
+        .ok_or_else(|| nom::Err::Failure(VerboseError::from_char(input, '^')))?;
+    let size = Point::from((grid.height(), grid.width()));
+    Ok((
+        remaining,
+        InputData {
+            obstacles: grid.find_all(&b'#').map(Point::from).collect(),
+            start: Point::from(start),
+            rows: 0..size.row,
+            columns: 0..size.col,
+        },
+    ))
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part1(input: &InputData) -> AocResult<usize> {
+    Ok(input.walk().map(|g| g.location).unique().count())
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part2(input: &InputData) -> AocResult<usize> {
+    Ok(input
+        .walk()
+        .skip(1)
+        .map(|g| g.location)
+        .unique()
+        .par_bridge()
+        .filter(|p| !input.alternate(*p).walk().all_unique())
+        .count())
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = InputData;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> ParseResult<'_, InputData> {
+        parse(input)
+    }
+
+    fn part1(input: &InputData) -> AocResult<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &InputData) -> AocResult<usize> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn test_parser() {
+        assert_parser!(
+            parse,
+            INPUT,
+            InputData {
+                obstacles: HashSet::from([
+                    Point { row: 0, col: 4 },
+                    Point { row: 7, col: 8 },
+                    Point { row: 3, col: 2 },
+                    Point { row: 1, col: 9 },
+                    Point { row: 4, col: 7 },
+                    Point { row: 8, col: 0 },
+                    Point { row: 9, col: 6 },
+                    Point { row: 6, col: 1 }
+                ]),
+                start: Point { row: 6, col: 4 },
+                rows: 0..10,
+                columns: 0..10
+            }
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_part!(parse, part1, INPUT, 41);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_part!(parse, part2, INPUT, 6);
+    }
+}
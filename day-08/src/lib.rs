@@ -0,0 +1,216 @@
+use itertools::Itertools;
+use std::ops::Range;
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+use utils::{geom::Point, grid::Grid};
+
+/// Antinodes of a pair of antennas on the same frequency
+pub trait Antinode {
+    /// The point across from `self` of a circle centered on `other`.
+    ///
+    /// | Label | Name     |
+    /// | ----- | -------- |
+    /// | `S`   | self     |
+    /// | `O`   | other    |
+    /// | `A`   | antinode |
+    ///
+    /// ```ascii
+    /// .......
+    /// .....A.
+    /// ..../..
+    /// ...O...
+    /// ../....
+    /// .S.....
+    /// .......
+    /// ```
+    fn antinode(&self, other: &Self) -> Self;
+
+    /// The points every N distance on a ray starting on `self` going through
+    /// `other` where N is the distance between `self` and `other`. The first
+    /// element is the antinode of `self` to `other`.
+    ///
+    /// | Label | Name            |
+    /// | ----- | --------------- |
+    /// | `S`   | self            |
+    /// | `O`   | other           |
+    /// | `A`   | first antinode  |
+    /// | `B`   | second antinode |
+    ///
+    /// ```ascii
+    /// .........
+    /// .......B.
+    /// ....../..
+    /// .....A...
+    /// ..../....
+    /// ...O.....
+    /// ../......
+    /// .S.......
+    /// .........
+    /// ```
+    fn antinodes(&self, other: &Self) -> Antinodes;
+}
+
+impl Antinode for Point {
+    fn antinode(&self, other: &Self) -> Self {
+        *other + (*other - *self)
+    }
+
+    fn antinodes(&self, other: &Self) -> Antinodes {
+        Antinodes {
+            p1: *self,
+            p2: *other,
+        }
+    }
+}
+
+/// The points every N distance on a ray starting on `self` going through
+/// `other` where N is the distance between `self` and `other`. The first
+/// element is the antinode of `self` to `other`. See also
+/// [antinodes function](Antinode::antinodes)
+#[derive(Debug, Clone)]
+pub struct Antinodes {
+    p1: Point,
+    p2: Point,
+}
+
+impl Iterator for Antinodes {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.p1, self.p2) = (self.p2, self.p1.antinode(&self.p2));
+        Some(self.p2)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    nodes: Vec<Vec<Point>>,
+    rows: Range<isize>,
+    cols: Range<isize>,
+}
+
+impl InputData {
+    /// Determines if `point` is on the map
+    #[must_use]
+    pub fn contains(&self, point: &Point) -> bool {
+        self.rows.contains(&point.row) && self.cols.contains(&point.col)
+    }
+}
+
+fn parse(input: &str) -> ParseResult<'_, InputData> {
+    let (input, grid) = Grid::parse(input)?;
+    let rows = 0..grid.height() as isize;
+    let cols = 0..grid.width() as isize;
+    let nodes = grid
+        .iter()
+        .filter(|(_, &c)| c != b'.')
+        .map(|(position, &c)| (c, Point::from(position)))
+        .into_group_map()
+        .into_iter()
+        .sorted_unstable_by_key(|(c, _)| *c)
+        .map(|(_, nodes)| nodes)
+        .collect_vec();
+    Ok((input, InputData { nodes, rows, cols }))
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part1(input: &InputData) -> AocResult<usize> {
+    Ok(input
+        .nodes
+        .iter()
+        .flat_map(|ant| ant.iter().tuple_combinations())
+        .flat_map(|(p1, p2)| [p1.antinode(p2), p2.antinode(p1)])
+        .filter(|p| input.contains(p))
+        .unique()
+        .count())
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part2(input: &InputData) -> AocResult<usize> {
+    Ok(input
+        .nodes
+        .iter()
+        .flat_map(|ant| ant.iter().tuple_combinations())
+        .flat_map(|(p1, p2)| {
+            let p1_p2 = p1.antinodes(p2).take_while(|p| input.contains(p));
+            let p2_p1 = p2.antinodes(p1).take_while(|p| input.contains(p));
+            [*p1, *p2].into_iter().chain(p1_p2).chain(p2_p1)
+        })
+        .unique()
+        .count())
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = InputData;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> ParseResult<'_, InputData> {
+        parse(input)
+    }
+
+    fn part1(input: &InputData) -> AocResult<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &InputData) -> AocResult<usize> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn test_parser() {
+        assert_parser!(
+            parse,
+            INPUT,
+            InputData {
+                nodes: vec![
+                    vec![
+                        Point { row: 1, col: 8 },
+                        Point { row: 2, col: 5 },
+                        Point { row: 3, col: 7 },
+                        Point { row: 4, col: 4 },
+                    ],
+                    vec![
+                        Point { row: 5, col: 6 },
+                        Point { row: 8, col: 8 },
+                        Point { row: 9, col: 9 },
+                    ]
+                ],
+                rows: 0..12,
+                cols: 0..12
+            }
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_part!(parse, part1, INPUT, 14);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_part!(parse, part2, INPUT, 34);
+    }
+}
@@ -0,0 +1,199 @@
+use std::slice::Iter;
+
+use itertools::{chain, repeat_n, Itertools};
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+/// A single block of the disk
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DiskData {
+    File(usize),
+    FreeSpace,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    fs: Vec<DiskData>,
+}
+
+impl InputData {
+    /// File ids of the disk blocks after compacting, see [`Defragment`]
+    #[must_use]
+    pub fn defrag(&self) -> Defragment<'_> {
+        Defragment {
+            list: self.fs.iter(),
+        }
+    }
+}
+
+fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::{
+        branch::alt,
+        character::complete::{line_ending, one_of},
+        combinator::{eof, map, map_opt},
+        multi::many1,
+        sequence::terminated,
+    };
+
+    let digit = map_opt(one_of("0123456789"), |c: char| {
+        c.to_digit(10)?.try_into().ok()
+    });
+    let sizes = terminated(many1(digit), alt((line_ending, eof)));
+    let mut parser = map(sizes, |sizes: Vec<usize>| InputData {
+        fs: sizes
+            .into_iter()
+            .chain([0])
+            .tuples()
+            .enumerate()
+            .flat_map(|(id, (file, space))| {
+                chain!(
+                    repeat_n(DiskData::File(id), file),
+                    repeat_n(DiskData::FreeSpace, space)
+                )
+            })
+            .collect_vec(),
+    });
+    parser(input)
+}
+
+/// File ids of the compacted disk, in block order
+///
+/// Free space is filled by moving blocks from the end of the disk one at a
+/// time, so the iterator stops once there are no more files to move.
+#[derive(Debug, Clone)]
+pub struct Defragment<'a> {
+    list: Iter<'a, DiskData>,
+}
+
+impl Iterator for Defragment<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list
+            .next()
+            .and_then(|d| match d {
+                DiskData::File(id) => Some(id),
+                DiskData::FreeSpace => self
+                    .list
+                    .rfind(|d| matches!(d, DiskData::File(_)))
+                    .and_then(|d| match d {
+                        DiskData::File(id) => Some(id),
+                        DiskData::FreeSpace => None,
+                    }),
+            })
+            .copied()
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part1(input: &InputData) -> AocResult<usize> {
+    Ok(input.defrag().enumerate().map(|(i, id)| i * id).sum())
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part2(_input: &InputData) -> AocResult<()> {
+    Ok(())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = InputData;
+    type Part1 = usize;
+    type Part2 = ();
+
+    fn parse(input: &str) -> ParseResult<'_, InputData> {
+        parse(input)
+    }
+
+    fn part1(input: &InputData) -> AocResult<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &InputData) -> AocResult<()> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "2333133121414131402";
+
+    #[test]
+    fn test_parser() {
+        use DiskData::*;
+
+        assert_parser!(
+            parse,
+            INPUT,
+            InputData {
+                fs: vec![
+                    File(0),
+                    File(0),
+                    FreeSpace,
+                    FreeSpace,
+                    FreeSpace,
+                    File(1),
+                    File(1),
+                    File(1),
+                    FreeSpace,
+                    FreeSpace,
+                    FreeSpace,
+                    File(2),
+                    FreeSpace,
+                    FreeSpace,
+                    FreeSpace,
+                    File(3),
+                    File(3),
+                    File(3),
+                    FreeSpace,
+                    File(4),
+                    File(4),
+                    FreeSpace,
+                    File(5),
+                    File(5),
+                    File(5),
+                    File(5),
+                    FreeSpace,
+                    File(6),
+                    File(6),
+                    File(6),
+                    File(6),
+                    FreeSpace,
+                    File(7),
+                    File(7),
+                    File(7),
+                    FreeSpace,
+                    File(8),
+                    File(8),
+                    File(8),
+                    File(8),
+                    File(9),
+                    File(9),
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_parser_trailing_newline() {
+        let (remaining, parsed) = parse("2333133121414131402\n").unwrap();
+        assert_eq!(remaining, "");
+        assert_eq!(parsed, parse(INPUT).unwrap().1);
+        assert!(parse("12x").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_part!(parse, part1, INPUT, 1928);
+    }
+
+    #[test]
+    fn test_part2() {
+        // assert_part!(parse, part2, INPUT, ());
+    }
+}
@@ -0,0 +1,43 @@
+//! Generates the registry of days from the `day-*` dependencies of
+//! `Cargo.toml`, which `cargo xtask create` adds for every new day
+use std::{env, fs, path::Path};
+
+/// Folder and type of the solution of a day package: `day-05` is
+/// `day_05::Day05` in `day-05`, `day-2023-05` is `day_2023_05::Day05` in
+/// `2023/day-05`
+fn registry_entry(package: &str) -> Option<String> {
+    let rest = package.strip_prefix("day-")?;
+    let (folder, day) = match rest.split_once('-') {
+        Some((year, day)) => (format!("{year}/day-{day}"), day),
+        None => (package.to_string(), rest),
+    };
+    let krate = package.replace('-', "_");
+    Some(format!(
+        "    (\"{folder}\", Day::of::<{krate}::Day{day}>()),\n"
+    ))
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    let manifest: toml::Table = fs::read_to_string("Cargo.toml")
+        .expect("Could not read Cargo.toml")
+        .parse()
+        .expect("Invalid Cargo.toml");
+    let dependencies = manifest
+        .get("dependencies")
+        .and_then(toml::Value::as_table)
+        .expect("No dependencies in Cargo.toml");
+
+    let mut registry = "/// Folder and solution of every linked day, in order\n\
+                        const DAYS: &[(&str, Day)] = &[\n"
+        .to_string();
+    for package in dependencies.keys() {
+        if let Some(entry) = registry_entry(package) {
+            registry.push_str(&entry);
+        }
+    }
+    registry.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("days.rs"), registry).expect("Could not write days.rs");
+}
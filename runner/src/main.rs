@@ -0,0 +1,207 @@
+use anyhow::anyhow;
+use clap::Parser;
+use std::{
+    any::Any,
+    env, fs, io,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use utils::{table::print_table, Answers, AocResult, Day, Phase, Report, SolutionPart};
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+const HEADER: [&str; 7] = [
+    "Day", "Answer 1", "Answer 2", "Parse", "Part 1", "Part 2", "Total",
+];
+
+/// Runs several days in one process and prints a table of answers and times
+#[derive(Debug, Parser)]
+#[command(author, about, long_about = None)]
+struct Cli {
+    /// Days to run: a day, a range like `1..=9` or `3..6`, or `all`
+    #[arg(short, long, default_value = "all", value_parser = parse_days)]
+    day: RangeInclusive<u8>,
+
+    /// Year of the days to run, from the `YYYY/day-XX` folders, instead of
+    /// the days of the `day-XX` folders
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Workspace folder with the `day-XX/input.txt` files, by default the
+    /// workspace of the current folder
+    #[arg(long)]
+    root: Option<PathBuf>,
+}
+
+/// Closest folder from the current one up with a workspace `Cargo.toml`, or
+/// the current folder when there is none
+fn workspace_root() -> io::Result<PathBuf> {
+    let current = env::current_dir()?;
+    let root = current.ancestors().find(|folder| {
+        fs::read_to_string(folder.join("Cargo.toml"))
+            .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+    });
+    Ok(root.unwrap_or(&current).to_path_buf())
+}
+
+fn parse_days(days: &str) -> Result<RangeInclusive<u8>, String> {
+    let day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("{day} is not a day"))
+    };
+    if days == "all" {
+        Ok(1..=25)
+    } else if let Some((start, end)) = days.split_once("..=") {
+        Ok(day(start)?..=day(end)?)
+    } else if let Some((start, end)) = days.split_once("..") {
+        Ok(day(start)?..=day(end)?.saturating_sub(1))
+    } else {
+        let day = day(days)?;
+        Ok(day..=day)
+    }
+}
+
+/// What happened when running a day
+enum Outcome {
+    /// There is no `input.txt` for the day
+    NoInput,
+    Failed(anyhow::Error),
+    Ran(Report),
+}
+
+/// Determines if a folder of the registry holds a day of the year, or a day
+/// of the `day-XX` folders without a year
+fn in_year(folder: &str, year: Option<u16>) -> bool {
+    match (folder.split_once('/'), year) {
+        (Some((folder_year, _)), Some(year)) => folder_year == year.to_string(),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Message of a panic, when it has one
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message")
+}
+
+/// Runs a day on its `input.txt`, checking against `answers.toml` when
+/// there is one
+///
+/// A panicking day is reported as failed, so the other days still run.
+fn run(root: &Path, name: &str, day: &Day) -> Outcome {
+    let folder = root.join(name);
+    let Ok(input) = fs::read_to_string(folder.join("input.txt")) else {
+        return Outcome::NoInput;
+    };
+    let answers_file = folder.join("answers.toml");
+    let report = (|| {
+        let mut report = Report::quiet(name);
+        if answers_file.exists() {
+            report = report.expecting(Answers::load(&answers_file)?);
+        }
+        panic::catch_unwind(AssertUnwindSafe(|| day.run(&input, &mut report)))
+            .map_err(|payload| anyhow!("panicked: {}", panic_message(payload.as_ref())))??;
+        AocResult::Ok(report)
+    })();
+    report.map_or_else(Outcome::Failed, Outcome::Ran)
+}
+
+/// Cells of the table row for a day
+fn row(day: &Day, outcome: &Outcome) -> [String; 7] {
+    let time = |duration: Option<Duration>| duration.map_or(String::new(), |d| format!("{d:.2?}"));
+    let mut row = [
+        day.number.to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+    ];
+    match outcome {
+        Outcome::NoInput => row[1] = "no input".to_string(),
+        Outcome::Failed(_) => row[1] = "error".to_string(),
+        Outcome::Ran(report) => {
+            let answer = |part: SolutionPart| {
+                let answer = report
+                    .answers()
+                    .iter()
+                    .find(|(answered, _)| *answered == part)
+                    .map_or("", |(_, answer)| answer.as_str());
+                match report.verified(part) {
+                    Some(true) => format!("{answer} ✓"),
+                    Some(false) => format!("{answer} ✗"),
+                    None => answer.to_string(),
+                }
+            };
+            let timings = report.timings();
+            row[1] = answer(SolutionPart::PartOne);
+            row[2] = answer(SolutionPart::PartTwo);
+            row[3] = time(timings.get(Phase::Parse));
+            row[4] = time(timings.get(Phase::PartOne));
+            row[5] = time(timings.get(Phase::PartTwo));
+            row[6] = time(Some(timings.total()));
+        }
+    }
+    row
+}
+
+/// Determines if the day ran and none of its answers were wrong
+fn passed(outcome: &Outcome) -> bool {
+    match outcome {
+        Outcome::NoInput => true,
+        Outcome::Failed(_) => false,
+        Outcome::Ran(report) => [SolutionPart::PartOne, SolutionPart::PartTwo]
+            .into_iter()
+            .all(|part| report.verified(part) != Some(false)),
+    }
+}
+
+fn main() -> AocResult<()> {
+    let cli = Cli::parse();
+    let root = match &cli.root {
+        Some(root) => root.clone(),
+        None => workspace_root()?,
+    };
+    let outcomes: Vec<_> = DAYS
+        .iter()
+        .filter(|(folder, day)| in_year(folder, cli.year) && cli.day.contains(&day.number))
+        .map(|(folder, day)| (day, run(&root, folder, day)))
+        .collect();
+    let rows: Vec<_> = outcomes
+        .iter()
+        .map(|(day, outcome)| row(day, outcome))
+        .collect();
+    print_table(HEADER, &rows);
+
+    let total: Duration = outcomes
+        .iter()
+        .filter_map(|(_, outcome)| match outcome {
+            Outcome::Ran(report) => Some(report.timings().total()),
+            _ => None,
+        })
+        .sum();
+    println!("\nTotal: {total:.2?}");
+
+    for (day, outcome) in &outcomes {
+        if let Outcome::Failed(error) = outcome {
+            eprintln!("\nDay {}: {error:#}", day.number);
+        }
+    }
+    let failed = outcomes
+        .iter()
+        .filter(|(_, outcome)| !passed(outcome))
+        .count();
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(anyhow!("{failed} of {} days failed", outcomes.len()))
+    }
+}
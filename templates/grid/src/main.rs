@@ -0,0 +1,5 @@
+use {{crate}}::Day{{day_padded}};
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+aoc_main!(Day{{day_padded}});
@@ -0,0 +1,63 @@
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[derive(Debug, PartialEq)]
+pub struct InputData {}
+
+fn parse(_input: &str) -> ParseResult<'_, InputData> {
+    todo!()
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part1(_input: &InputData) -> AocResult<()> {
+    Ok(())
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part2(_input: &InputData) -> AocResult<()> {
+    Ok(())
+}
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = {{day}};
+
+    type Input = InputData;
+    type Part1 = ();
+    type Part2 = ();
+
+    fn parse(input: &str) -> ParseResult<'_, InputData> {
+        parse(input)
+    }
+
+    fn part1(input: &InputData) -> AocResult<()> {
+        part1(input)
+    }
+
+    fn part2(input: &InputData) -> AocResult<()> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "";
+
+    #[test]
+    fn test_parser() {
+        assert_parser!(parse, INPUT, InputData {});
+    }
+
+    #[test]
+    fn test_part1() {
+        // assert_part!(parse, part1, INPUT, ());
+    }
+
+    #[test]
+    fn test_part2() {
+        // assert_part!(parse, part2, INPUT, ());
+    }
+}
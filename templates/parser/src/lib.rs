@@ -0,0 +1,82 @@
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    lines: Vec<Vec<u32>>,
+}
+
+fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::{
+        character::complete::{line_ending, space1, u32},
+        combinator::map,
+        error::context,
+        multi::separated_list1,
+    };
+
+    let line = context("line", separated_list1(space1, u32));
+    let lines = separated_list1(line_ending, line);
+    let mut parser = map(lines, |lines| InputData { lines });
+    parser(input)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part1(_input: &InputData) -> AocResult<()> {
+    Ok(())
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn part2(_input: &InputData) -> AocResult<()> {
+    Ok(())
+}
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = {{day}};
+
+    type Input = InputData;
+    type Part1 = ();
+    type Part2 = ();
+
+    fn parse(input: &str) -> ParseResult<'_, InputData> {
+        parse(input)
+    }
+
+    fn part1(input: &InputData) -> AocResult<()> {
+        part1(input)
+    }
+
+    fn part2(input: &InputData) -> AocResult<()> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1 2 3
+4 5 6";
+
+    #[test]
+    fn test_parser() {
+        assert_parser!(
+            parse,
+            INPUT,
+            InputData {
+                lines: vec![vec![1, 2, 3], vec![4, 5, 6]]
+            }
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        // assert_part!(parse, part1, INPUT, ());
+    }
+
+    #[test]
+    fn test_part2() {
+        // assert_part!(parse, part2, INPUT, ());
+    }
+}
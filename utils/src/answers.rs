@@ -0,0 +1,116 @@
+use crate::{AocResult, SolutionPart};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, fs, path::Path};
+
+/// Known good answers for a day, stored as `answers.toml`
+///
+/// ```toml
+/// part1 = "11"
+/// part2 = "31"
+/// ```
+///
+/// Answers are kept as the website takes them, see [`Answers::normalize`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    /// Text of an answer as it is checked, recorded and submitted: its
+    /// [`Debug`] form, without the quotes and escapes of a string or char
+    pub fn normalize(answer: &impl Debug) -> String {
+        let debug = format!("{answer:?}");
+        unquote(&debug).unwrap_or(debug)
+    }
+
+    /// Reads the answers from a toml file
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be read or isn't valid toml
+    pub fn load(path: &Path) -> AocResult<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Could not read answers from {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    /// Writes the answers to a toml file
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be written
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Could not write answers to {}", path.display()))
+    }
+
+    /// Answer recorded for a part
+    #[must_use]
+    pub fn get(&self, part: SolutionPart) -> Option<&str> {
+        match part {
+            SolutionPart::PartOne => self.part1.as_deref(),
+            SolutionPart::PartTwo => self.part2.as_deref(),
+            SolutionPart::Both => None,
+        }
+    }
+
+    /// Sets the answer for a part
+    pub fn set(&mut self, part: SolutionPart, answer: String) {
+        match part {
+            SolutionPart::PartOne => self.part1 = Some(answer),
+            SolutionPart::PartTwo => self.part2 = Some(answer),
+            SolutionPart::Both => (),
+        }
+    }
+}
+
+/// Undoes the quotes and escapes [`Debug`] adds to a string or char, if
+/// `debug` is one
+fn unquote(debug: &str) -> Option<String> {
+    let quoted = debug
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .or_else(|| debug.strip_prefix('\'')?.strip_suffix('\''))?;
+    let mut text = String::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => text.push('\n'),
+            'r' => text.push('\r'),
+            't' => text.push('\t'),
+            '0' => text.push('\0'),
+            'u' => {
+                let code = chars.as_str().strip_prefix('{')?;
+                let (hex, rest) = code.split_once('}')?;
+                text.push(char::from_u32(u32::from_str_radix(hex, 16).ok()?)?);
+                chars = rest.chars();
+            }
+            escaped => text.push(escaped),
+        }
+    }
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(Answers::normalize(&1928), "1928");
+        assert_eq!(Answers::normalize(&-3i64), "-3");
+        assert_eq!(Answers::normalize(&"4,6,3,5".to_string()), "4,6,3,5");
+        assert_eq!(Answers::normalize(&"a\"b\\c\té"), "a\"b\\c\té");
+        assert_eq!(Answers::normalize(&"\u{7f}"), "\u{7f}");
+        assert_eq!(Answers::normalize(&'x'), "x");
+        assert_eq!(Answers::normalize(&Some(2)), "Some(2)");
+    }
+}
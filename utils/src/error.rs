@@ -0,0 +1,225 @@
+use crate::ParseResult;
+use nom::{
+    error::{VerboseError, VerboseErrorKind},
+    Finish,
+};
+use std::fmt::{Display, Write};
+
+/// A parser failure rendered against the input it happened in
+///
+/// ```text
+/// Parse error at line 2, column 3:
+///   |
+/// 2 | 1 x 3
+///   |   ^ expected digit
+///   = while parsing report at line 2, column 1
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+/// Line and column (both starting at 1) of `offset` bytes into `input`, and
+/// the text of that line
+fn locate(input: &str, offset: usize) -> (usize, usize, &str) {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line_end = input[offset..]
+        .find('\n')
+        .map_or(input.len(), |i| offset + i);
+    let line = before.matches('\n').count() + 1;
+    let column = input[line_start..offset].chars().count() + 1;
+    (
+        line,
+        column,
+        input[line_start..line_end].trim_end_matches('\r'),
+    )
+}
+
+/// Offset of `part` into `input`, if `part` is a slice of `input`
+///
+/// The slices of parser errors usually are, but not when the parser worked on
+/// a copy of the input or returned a `&'static str`.
+fn offset_in(input: &str, part: &str) -> Option<usize> {
+    let offset = (part.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    (offset + part.len() <= input.len() && input.is_char_boundary(offset)).then_some(offset)
+}
+
+/// Location of `part` in `input` like [`locate`], or the start of `input`
+/// when `part` isn't a slice of it
+fn locate_slice<'a>(input: &'a str, part: &str) -> (usize, usize, &'a str) {
+    locate(input, offset_in(input, part).unwrap_or(0))
+}
+
+fn describe(kind: &VerboseErrorKind) -> String {
+    match kind {
+        VerboseErrorKind::Context(context) => format!("while parsing {context}"),
+        VerboseErrorKind::Char(c) => format!("expected '{c}'"),
+        VerboseErrorKind::Nom(kind) => format!("expected {}", kind.description().to_lowercase()),
+    }
+}
+
+impl ParseError {
+    /// Renders the error chain from `error` against the original `input`
+    #[must_use]
+    pub fn new(input: &str, error: &VerboseError<&str>) -> Self {
+        let Some(((remaining, kind), context)) = error.errors.split_first() else {
+            return Self {
+                line: 1,
+                column: 1,
+                message: "Parse error".to_string(),
+            };
+        };
+        let mut error = Self::at(input, remaining, "Parse error", &describe(kind));
+        let gutter = error.line.to_string().len();
+        for (remaining, kind) in context {
+            let (line, column, _) = locate_slice(input, remaining);
+            let _ = write!(
+                error.message,
+                "\n{:gutter$} = {} at line {line}, column {column}",
+                "",
+                describe(kind)
+            );
+        }
+        error
+    }
+
+    /// Error for input the parser finished without reading
+    #[must_use]
+    pub fn unconsumed(input: &str, remaining: &str) -> Self {
+        let skipped = remaining.len() - remaining.trim_start().len();
+        Self::at(
+            input,
+            &remaining[skipped..],
+            "Unparsed input",
+            "parser stopped here",
+        )
+    }
+
+    /// Renders `title` with a snippet pointing at the start of `remaining`
+    fn at(input: &str, remaining: &str, title: &str, note: &str) -> Self {
+        let (line, column, text) = locate_slice(input, remaining);
+        let gutter = line.to_string().len();
+        let mut message = format!("{title} at line {line}, column {column}:\n");
+        let _ = writeln!(message, "{:gutter$} |", "");
+        let _ = writeln!(message, "{line} | {text}");
+        let _ = write!(message, "{:gutter$} | {:>column$} {note}", "", "^");
+        Self {
+            line,
+            column,
+            message,
+        }
+    }
+
+    /// Line of the input the parser failed on, starting at 1
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column of the input the parser failed on, starting at 1
+    #[must_use]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Unwraps the result of parsing all of `input`
+///
+/// Trailing whitespace is always allowed, anything else left unread is an
+/// error unless `allow_trailing` is set.
+///
+/// # Errors
+///
+/// If the parser failed or left input unread
+pub fn finish_parse<'a, T>(
+    input: &'a str,
+    parsed: ParseResult<'a, T>,
+    allow_trailing: bool,
+) -> Result<T, ParseError> {
+    let (remaining, parsed) = parsed
+        .finish()
+        .map_err(|error| ParseError::new(input, &error))?;
+    if allow_trailing || remaining.trim().is_empty() {
+        Ok(parsed)
+    } else {
+        Err(ParseError::unconsumed(input, remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{
+        character::complete::{line_ending, space1, u32},
+        error::context,
+        multi::separated_list1,
+    };
+
+    fn parse(input: &str) -> ParseResult<'_, Vec<Vec<u32>>> {
+        let report = context("report", separated_list1(space1, u32));
+        let mut parser = separated_list1(line_ending, report);
+        parser(input)
+    }
+
+    #[test]
+    fn test_location() {
+        assert_eq!(locate("ab\ncde\nf", 5), (2, 3, "cde"));
+        assert_eq!(locate("ab\r\ncd", 1), (1, 2, "ab"));
+    }
+
+    #[test]
+    fn test_slice_outside_input() {
+        let input = "abc\ndef";
+        let copy = input.to_uppercase();
+        let error = ParseError::unconsumed(input, &copy[4..]);
+        assert_eq!((error.line(), error.column()), (1, 1));
+        let error = VerboseError {
+            errors: vec![(&copy[copy.len()..], VerboseErrorKind::Char('x'))],
+        };
+        let error = ParseError::new(input, &error);
+        assert_eq!((error.line(), error.column()), (1, 1));
+        assert!(error.to_string().contains("1 | abc"));
+    }
+
+    #[test]
+    fn test_unconsumed() {
+        let input = "1 2 3\n\n4 5\n";
+        let (remaining, _) = parse(input).unwrap();
+        let error = ParseError::unconsumed(input, remaining);
+        assert_eq!((error.line(), error.column()), (3, 1));
+        assert_eq!(
+            error.to_string(),
+            "Unparsed input at line 3, column 1:
+  |
+3 | 4 5
+  | ^ parser stopped here"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "x 2 3\n";
+        let error = parse(input).finish().unwrap_err();
+        let error = ParseError::new(input, &error);
+        assert_eq!((error.line(), error.column()), (1, 1));
+        assert_eq!(
+            error.to_string(),
+            "Parse error at line 1, column 1:
+  |
+1 | x 2 3
+  | ^ expected digit
+  = while parsing report at line 1, column 1"
+        );
+    }
+}
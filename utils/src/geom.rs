@@ -0,0 +1,308 @@
+use crate::grid::{Grid, Position};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed `(row, col)` location or offset
+///
+/// Rows grow downwards, so [`Direction::North`] is a negative row offset.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    #[must_use]
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    /// Number of orthogonal steps between `self` and `other`
+    #[must_use]
+    pub const fn manhattan(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// The point one step in `direction`
+    #[must_use]
+    pub fn step(self, direction: impl Into<Self>) -> Self {
+        self + direction.into()
+    }
+
+    /// Determines if the point is inside `grid`
+    #[must_use]
+    pub fn within<T>(self, grid: &Grid<T>) -> bool {
+        self.position()
+            .is_some_and(|position| grid.contains(position))
+    }
+
+    /// The point as a grid position, if neither coordinate is negative
+    #[must_use]
+    pub fn position(self) -> Option<Position> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+}
+
+impl From<Position> for Point {
+    #[allow(clippy::cast_possible_wrap)]
+    fn from((row, col): Position) -> Self {
+        Self::new(row as isize, col as isize)
+    }
+}
+
+impl From<Point> for (isize, isize) {
+    fn from(point: Point) -> Self {
+        (point.row, point.col)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+/// The four orthogonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Turns 90° clockwise
+    #[must_use]
+    pub const fn rotate_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    /// Turns 90° counter-clockwise
+    #[must_use]
+    pub const fn rotate_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    /// Turns around
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// Offset of a single step in this direction
+    #[must_use]
+    pub const fn delta(self) -> Point {
+        match self {
+            Self::North => Point::new(-1, 0),
+            Self::East => Point::new(0, 1),
+            Self::South => Point::new(1, 0),
+            Self::West => Point::new(0, -1),
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        direction.delta()
+    }
+}
+
+impl From<Direction> for (isize, isize) {
+    fn from(direction: Direction) -> Self {
+        direction.delta().into()
+    }
+}
+
+/// The four orthogonal and four diagonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns 45° clockwise
+    #[must_use]
+    pub const fn rotate_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns 45° counter-clockwise
+    #[must_use]
+    pub const fn rotate_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Turns around
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// Offset of a single step in this direction
+    #[must_use]
+    pub const fn delta(self) -> Point {
+        match self {
+            Self::North => Point::new(-1, 0),
+            Self::NorthEast => Point::new(-1, 1),
+            Self::East => Point::new(0, 1),
+            Self::SouthEast => Point::new(1, 1),
+            Self::South => Point::new(1, 0),
+            Self::SouthWest => Point::new(1, -1),
+            Self::West => Point::new(0, -1),
+            Self::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Self::North,
+            Direction::East => Self::East,
+            Direction::South => Self::South,
+            Direction::West => Self::West,
+        }
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(direction: Direction8) -> Self {
+        direction.delta()
+    }
+}
+
+impl From<Direction8> for (isize, isize) {
+    fn from(direction: Direction8) -> Self {
+        direction.delta().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.rotate_right().rotate_left(), direction);
+            assert_eq!(direction.rotate_right().rotate_right(), direction.reverse());
+            assert_eq!(
+                direction.delta() + direction.reverse().delta(),
+                Point::ORIGIN
+            );
+        }
+        for direction in Direction8::ALL {
+            assert_eq!(direction.rotate_left().rotate_right(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(-direction.delta(), direction.reverse().delta());
+        }
+        assert_eq!(Direction8::NorthWest.rotate_right(), Direction8::North);
+        assert_eq!(Direction8::from(Direction::West), Direction8::West);
+    }
+
+    #[test]
+    fn test_point() {
+        let a = Point::new(1, 8);
+        let b = Point::new(2, 5);
+        assert_eq!(b + (b - a), Point::new(3, 2));
+        assert_eq!(a.manhattan(b), 4);
+        assert_eq!(a.step(Direction::North), Point::new(0, 8));
+        assert_eq!(Direction8::SouthWest.delta() * 3, Point::new(3, -3));
+        assert_eq!(Point::new(-1, 0).position(), None);
+        assert_eq!(Point::from((2, 3)).position(), Some((2, 3)));
+    }
+
+    #[test]
+    fn test_within() {
+        let grid = Grid::from_rows(vec![vec![0; 3]; 2]).unwrap();
+        assert!(Point::new(1, 2).within(&grid));
+        assert!(!Point::new(2, 2).within(&grid));
+        assert!(!Point::new(0, -1).within(&grid));
+        assert_eq!(grid.get_point(Point::new(1, 1)), Some(&0));
+        assert_eq!(
+            grid.ray((0, 0), Direction8::SouthEast).collect::<Vec<_>>(),
+            [(0, 0), (1, 1)]
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use crate::{
+    geom::{Direction, Direction8, Point},
+    ParseResult,
+};
+use nom::{
+    error::{ErrorKind, ParseError, VerboseError},
+    Offset,
+};
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+/// A `(row, col)` location in a [`Grid`]
+pub type Position = (usize, usize);
+
+/// A rectangular map of cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl Grid<u8> {
+    /// Parses a character map into its bytes
+    ///
+    /// The grid ends at the first blank line or the end of the input. The
+    /// line ending after the last row is left unparsed.
+    ///
+    /// # Errors
+    ///
+    /// If there are no rows or the rows aren't all the same length
+    pub fn parse(input: &str) -> ParseResult<'_, Self> {
+        Self::parse_with(input, |c| c)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, converting each byte with `f`
+    ///
+    /// See [`Grid::parse`]
+    ///
+    /// # Errors
+    ///
+    /// If there are no rows or the rows aren't all the same length
+    pub fn parse_with(input: &str, f: impl Fn(u8) -> T) -> ParseResult<'_, Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut end = 0;
+        for line in input.lines().take_while(|line| !line.is_empty()) {
+            if *width.get_or_insert(line.len()) != line.len() {
+                return Err(nom::Err::Error(VerboseError::from_error_kind(
+                    line,
+                    ErrorKind::Verify,
+                )));
+            }
+            cells.extend(line.bytes().map(&f));
+            end = input.offset(line) + line.len();
+        }
+        let Some(width) = width else {
+            return Err(nom::Err::Error(VerboseError::from_error_kind(
+                input,
+                ErrorKind::Many1,
+            )));
+        };
+        let height = cells.len() / width.max(1);
+        Ok((
+            &input[end..],
+            Self {
+                cells,
+                width,
+                height,
+            },
+        ))
+    }
+
+    /// Creates a grid from equally long rows
+    ///
+    /// Returns `None` if the rows have different lengths.
+    #[must_use]
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Number of columns
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// `(height, width)`, the shape of the grid as `ndarray` expects it
+    #[must_use]
+    pub const fn dim(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    /// Determines if `position` is inside the grid
+    #[must_use]
+    pub const fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    #[must_use]
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// The cells in row order
+    #[must_use]
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Takes the cells out of the grid in row order
+    #[must_use]
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// Iterates over the rows as slices
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over every position with its cell in row order
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / width, i % width), cell))
+    }
+
+    /// Cell at a signed `point`, if it's inside the grid
+    #[must_use]
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.position()?)
+    }
+
+    /// Position one `step` from `position`, if it's inside the grid
+    ///
+    /// `step` is a `(row, col)` offset or a direction from [`crate::geom`].
+    #[must_use]
+    pub fn step(&self, (row, col): Position, step: impl Into<(isize, isize)>) -> Option<Position> {
+        let (d_row, d_col) = step.into();
+        let position = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    /// The up to four orthogonal neighbours of `position` inside the grid,
+    /// clockwise from north
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The up to eight neighbours of `position`, diagonals included, inside
+    /// the grid, clockwise from north
+    pub fn all_neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Positions starting at `position` and moving by `step` until leaving
+    /// the grid
+    ///
+    /// The first item is `position` itself, if it is inside the grid.
+    pub fn ray(&self, position: Position, step: impl Into<(isize, isize)>) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            next: Some(position).filter(|&position| self.contains(position)),
+            step: step.into(),
+        }
+    }
+
+    /// Position of the first cell equal to `value` in row order
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// Positions of every cell equal to `value` in row order
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// Converts every cell with `f`
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).expect("position outside of the grid")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .expect("position outside of the grid")
+    }
+}
+
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", (*cell).into())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Positions along a straight line through a [`Grid`]. See also
+/// [ray function](Grid::ray)
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    next: Option<Position>,
+    step: (isize, isize),
+}
+
+impl<T> Iterator for Ray<'_, T> {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = self.grid.step(current, self.step);
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "..#
+.^.
+#..
+
+moves";
+
+    #[test]
+    fn test_parser() {
+        let (remaining, grid) = Grid::parse(INPUT).unwrap();
+        assert_eq!(remaining, "\n\nmoves");
+        assert_eq!(
+            grid,
+            Grid::from_rows(vec![b"..#".to_vec(), b".^.".to_vec(), b"#..".to_vec()]).unwrap()
+        );
+        assert_eq!(grid.to_string(), "..#\n.^.\n#..");
+        assert!(Grid::parse("..\n...").is_err());
+        assert!(Grid::parse("").is_err());
+    }
+
+    #[test]
+    fn test_find() {
+        let (_, grid) = Grid::parse(INPUT).unwrap();
+        assert_eq!(grid.find(&b'^'), Some((1, 1)));
+        assert_eq!(grid.find(&b'X'), None);
+        assert_eq!(grid.find_all(&b'#').collect::<Vec<_>>(), [(0, 2), (2, 0)]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let (_, grid) = Grid::parse(INPUT).unwrap();
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.all_neighbours((1, 1)).count(), 8);
+        assert_eq!(
+            grid.all_neighbours((2, 2)).collect::<Vec<_>>(),
+            [(1, 2), (2, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let (_, grid) = Grid::parse(INPUT).unwrap();
+        assert_eq!(
+            grid.ray((2, 0), (-1, 1)).collect::<Vec<_>>(),
+            [(2, 0), (1, 1), (0, 2)]
+        );
+        assert_eq!(grid.ray((0, 0), (0, -1)).collect::<Vec<_>>(), [(0, 0)]);
+        assert_eq!(grid.ray((3, 0), (0, 1)).count(), 0);
+    }
+}
@@ -1,9 +1,28 @@
 pub use anyhow::Result as AocResult;
 pub use clap::Parser;
 use clap::{builder::PossibleValue, ValueEnum};
-use std::path::PathBuf;
+use std::{fmt::Display, path::PathBuf};
 
-pub type ParseResult<'a, T> = nom::IResult<&'a str, T>;
+mod answers;
+mod error;
+pub mod geom;
+pub mod grid;
+mod memory;
+mod report;
+mod solution;
+pub mod table;
+mod timing;
+
+pub use answers::Answers;
+pub use error::{finish_parse, ParseError};
+#[cfg(feature = "count-alloc")]
+pub use memory::CountingAllocator;
+pub use memory::{MemStats, Memory, COUNTING};
+pub use report::{OutputFormat, Report};
+pub use solution::{run, Day, Solution};
+pub use timing::{Phase, Timings};
+
+pub type ParseResult<'a, T> = nom::IResult<&'a str, T, nom::error::VerboseError<&'a str>>;
 
 #[derive(Debug, Parser)]
 #[command(author, about, long_about = None)]
@@ -14,6 +33,35 @@ pub struct Cli {
     /// Part of the task to do
     #[arg(short, long, value_enum, default_value_t)]
     part: SolutionPart,
+
+    /// Print how long parsing and each part took
+    #[arg(long)]
+    time: bool,
+
+    /// Output format of the answers
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+
+    /// Answers file to check against [default: answers.toml next to the input file]
+    #[arg(long, value_name = "FILE")]
+    expect: Option<PathBuf>,
+
+    /// Don't check the answers against any answers file
+    #[arg(long, conflicts_with_all = ["expect", "record"])]
+    no_expect: bool,
+
+    /// Save the answers to the answers file instead of checking them
+    #[arg(long)]
+    record: bool,
+
+    /// Don't fail when the parser leaves non-whitespace input unread
+    #[arg(long)]
+    allow_trailing: bool,
+
+    /// Print the allocations and peak heap of each phase, needs the
+    /// `count-alloc` feature of utils
+    #[arg(long)]
+    mem: bool,
 }
 
 impl Cli {
@@ -26,6 +74,43 @@ impl Cli {
         Ok(std::fs::read_to_string(&self.input_file)?)
     }
 
+    /// Location of the answers file for the input
+    #[must_use]
+    pub fn answers_file(&self) -> PathBuf {
+        self.expect
+            .clone()
+            .unwrap_or_else(|| self.input_file.with_file_name("answers.toml"))
+    }
+
+    /// Loads the answers to check against, if there are any
+    ///
+    /// The default answers file is optional, but one given with `--expect`
+    /// must exist. There are none with `--no-expect`.
+    ///
+    /// # Errors
+    ///
+    /// Check errors for [`Answers::load`]
+    pub fn expected_answers(&self) -> AocResult<Option<Answers>> {
+        let path = self.answers_file();
+        if self.record || self.no_expect || (self.expect.is_none() && !path.exists()) {
+            Ok(None)
+        } else {
+            Answers::load(&path).map(Some)
+        }
+    }
+
+    /// Determines if the parser may leave input unread
+    #[must_use]
+    pub const fn allow_trailing(&self) -> bool {
+        self.allow_trailing
+    }
+
+    /// Determines if the answers should be recorded instead of checked
+    #[must_use]
+    pub const fn record(&self) -> bool {
+        self.record
+    }
+
     /// Determines if the part should run based on cli flags
     #[must_use]
     pub fn should_run(&self, part: SolutionPart) -> bool {
@@ -35,6 +120,30 @@ impl Cli {
             self.part == part
         }
     }
+
+    /// Part of the task requested on the command line
+    #[must_use]
+    pub const fn part(&self) -> SolutionPart {
+        self.part
+    }
+
+    /// Determines if the timing breakdown should be printed
+    #[must_use]
+    pub const fn time(&self) -> bool {
+        self.time
+    }
+
+    /// Format the answers should be printed in
+    #[must_use]
+    pub const fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Determines if the heap use of each phase should be reported
+    #[must_use]
+    pub const fn mem(&self) -> bool {
+        self.mem
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -45,6 +154,28 @@ pub enum SolutionPart {
     Both,
 }
 
+impl SolutionPart {
+    /// Name used for the part in machine-readable output
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::PartOne => "part1",
+            Self::PartTwo => "part2",
+            Self::Both => "both",
+        }
+    }
+}
+
+impl Display for SolutionPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PartOne => write!(f, "Part 1"),
+            Self::PartTwo => write!(f, "Part 2"),
+            Self::Both => write!(f, "Both parts"),
+        }
+    }
+}
+
 impl ValueEnum for SolutionPart {
     fn value_variants<'a>() -> &'a [Self] {
         &[Self::PartOne, Self::PartTwo, Self::Both]
@@ -74,52 +205,66 @@ impl ValueEnum for SolutionPart {
 
 #[macro_export]
 macro_rules! aoc_main {
+    ($solution:ty) => {
+        $crate::count_allocations!();
+
+        fn main() -> AocResult<()> {
+            aoc_main!(@run, $crate::run::<$solution>)
+        }
+    };
+
     ($parse:ident, $part1:ident, $part2:ident) => {
+        $crate::count_allocations!();
+
         fn main() -> AocResult<()> {
-            let cli = Cli::parse();
-            let input = cli.input()?;
-            let parsed = $parse(&input);
-            let (_, parsed) = aoc_main!(@finalize, parsed);
-            if cli.should_run(SolutionPart::PartOne) {
-                let part1 = $part1(&parsed)?;
-                println!("Part 1: {:#?}", part1);
-            }
-            if cli.should_run(SolutionPart::PartTwo) {
-                let part2 = $part2(&parsed)?;
-                println!("Part 2: {:#?}", part2);
+            fn solve(input: &str, report: &mut $crate::Report) -> AocResult<()> {
+                let parsed = report.measure($crate::Phase::Parse, || $parse(input));
+                let parsed = report.finish_parse(input, parsed)?;
+                if report.should_run(SolutionPart::PartOne) {
+                    let part1 = report.measure($crate::Phase::PartOne, || $part1(&parsed))?;
+                    report.answer(SolutionPart::PartOne, &part1);
+                }
+                if report.should_run(SolutionPart::PartTwo) {
+                    let part2 = report.measure($crate::Phase::PartTwo, || $part2(&parsed))?;
+                    report.answer(SolutionPart::PartTwo, &part2);
+                }
+                Ok(())
             }
-            Ok(())
+
+            aoc_main!(@run, solve)
         }
     };
 
     ($parse1:ident, $parse2:ident, $part1:ident, $part2:ident) => {
+        $crate::count_allocations!();
+
         fn main() -> AocResult<()> {
-            let cli = Cli::parse();
-            let input = cli.input()?;
-            if cli.should_run(SolutionPart::PartOne) {
-                let parsed = $parse1(&input);
-                let (_, parsed) = aoc_main!(@finalize, parsed);
-                let part1 = $part1(&parsed)?;
-                println!("Part 1: {:#?}", part1);
+            fn solve(input: &str, report: &mut $crate::Report) -> AocResult<()> {
+                if report.should_run(SolutionPart::PartOne) {
+                    let parsed = report.measure($crate::Phase::ParseOne, || $parse1(input));
+                    let parsed = report.finish_parse(input, parsed)?;
+                    let part1 = report.measure($crate::Phase::PartOne, || $part1(&parsed))?;
+                    report.answer(SolutionPart::PartOne, &part1);
+                }
+                if report.should_run(SolutionPart::PartTwo) {
+                    let parsed = report.measure($crate::Phase::ParseTwo, || $parse2(input));
+                    let parsed = report.finish_parse(input, parsed)?;
+                    let part2 = report.measure($crate::Phase::PartTwo, || $part2(&parsed))?;
+                    report.answer(SolutionPart::PartTwo, &part2);
+                }
+                Ok(())
             }
-            if cli.should_run(SolutionPart::PartTwo) {
-                let parsed = $parse2(&input);
-                let (_, parsed) = aoc_main!(@finalize, parsed);
-                let part2 = $part2(&parsed)?;
-                println!("Part 2: {:#?}", part2);
-            }
-            Ok(())
+
+            aoc_main!(@run, solve)
         }
     };
 
-    (@finalize, $parsed:expr) => {
-        nom::Finish::finish($parsed).map_err(|nom::error::Error { input, code }| {
-            nom::error::Error {
-                input: input.to_string(),
-                code,
-            }
-        })?
-    }
+    (@run, $solve:expr) => {{
+        let cli = Cli::parse();
+        let mut report = $crate::Report::new(env!("CARGO_PKG_NAME"), &cli)?;
+        let result = cli.input().and_then(|input| $solve(&input, &mut report));
+        report.finish(result)
+    }};
 }
 
 #[macro_export]
@@ -0,0 +1,197 @@
+use crate::Phase;
+use std::fmt::Display;
+
+/// Whether the counting allocator is compiled in, with the `count-alloc`
+/// feature
+pub const COUNTING: bool = cfg!(feature = "count-alloc");
+
+#[cfg(feature = "count-alloc")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::{AtomicUsize, Ordering::Relaxed},
+    };
+
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    static BYTES: AtomicUsize = AtomicUsize::new(0);
+    static CURRENT: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+    /// The system allocator, counting allocations and the bytes on the heap
+    ///
+    /// Installed by [`aoc_main!`](crate::aoc_main) when the `count-alloc`
+    /// feature is enabled.
+    #[derive(Debug)]
+    pub struct CountingAllocator;
+
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(current, Relaxed);
+    }
+
+    // SAFETY: every call is forwarded to the system allocator unchanged
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            BYTES.fetch_add(layout.size(), Relaxed);
+            grow(layout.size());
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            BYTES.fetch_add(layout.size(), Relaxed);
+            grow(layout.size());
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            CURRENT.fetch_sub(layout.size(), Relaxed);
+            System.dealloc(ptr, layout);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            BYTES.fetch_add(new_size, Relaxed);
+            if new_size >= layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                CURRENT.fetch_sub(layout.size() - new_size, Relaxed);
+            }
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    /// Allocations and bytes allocated so far, and the peak heap since the
+    /// last call, which starts a new peak from the current heap
+    pub fn take() -> (usize, usize, usize) {
+        let peak = PEAK.swap(CURRENT.load(Relaxed), Relaxed);
+        (ALLOCATIONS.load(Relaxed), BYTES.load(Relaxed), peak)
+    }
+}
+
+#[cfg(feature = "count-alloc")]
+pub use counting::CountingAllocator;
+
+#[cfg(feature = "count-alloc")]
+use counting::take;
+
+/// Counters that stay at zero without the counting allocator
+#[cfg(not(feature = "count-alloc"))]
+const fn take() -> (usize, usize, usize) {
+    (0, 0, 0)
+}
+
+/// Installs [`CountingAllocator`] as the global allocator when the
+/// `count-alloc` feature is enabled, does nothing otherwise
+#[cfg(feature = "count-alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! count_allocations {
+    () => {
+        #[global_allocator]
+        static ALLOCATOR: $crate::CountingAllocator = $crate::CountingAllocator;
+    };
+}
+
+/// Installs `CountingAllocator` as the global allocator when the
+/// `count-alloc` feature is enabled, does nothing otherwise
+#[cfg(not(feature = "count-alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! count_allocations {
+    () => {};
+}
+
+/// Heap use of a phase of a solution
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemStats {
+    /// Number of allocations and reallocations
+    pub allocations: usize,
+    /// Bytes requested by those allocations
+    pub bytes: usize,
+    /// Largest size of the heap during the phase, including what was already
+    /// allocated before it
+    pub peak: usize,
+}
+
+/// Formats a number of bytes with a binary unit
+fn bytes(n: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = n as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{n} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+impl Display for MemStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            bytes(self.bytes),
+            bytes(self.peak)
+        )
+    }
+}
+
+/// Heap use of each phase of a solution, see [`MemStats`]
+#[derive(Debug, Default, Clone)]
+pub struct Memory {
+    phases: Vec<(Phase, MemStats)>,
+}
+
+impl Memory {
+    /// Runs `f` and records its heap use under `phase`
+    pub fn measure<T>(&mut self, phase: Phase, f: impl FnOnce() -> T) -> T {
+        let (allocations, bytes, _) = take();
+        let result = f();
+        let (allocations_after, bytes_after, peak) = take();
+        self.phases.push((
+            phase,
+            MemStats {
+                allocations: allocations_after - allocations,
+                bytes: bytes_after - bytes,
+                peak,
+            },
+        ));
+        result
+    }
+
+    /// Recorded phases in the order they ran
+    pub fn iter(&self) -> impl Iterator<Item = (Phase, MemStats)> + '_ {
+        self.phases.iter().copied()
+    }
+}
+
+impl Display for Memory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let phases: Vec<_> = self
+            .phases
+            .iter()
+            .map(|(phase, stats)| format!("{phase}: {stats}"))
+            .collect();
+        write!(f, "{}", phases.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(bytes(512), "512 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{
+    finish_parse, Answers, AocResult, Cli, Memory, ParseError, ParseResult, Phase, SolutionPart,
+    Timings, COUNTING,
+};
+use anyhow::anyhow;
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+use std::{
+    fmt::Debug,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+/// How the results of a run are written to stdout
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// `Part N: answer` lines for people
+    #[default]
+    Text,
+    /// A single JSON object with answers, timings (in nanoseconds) and errors
+    Json,
+}
+
+/// Collects the answers and timings of a run and prints them in the
+/// format requested on the command line
+#[derive(Debug)]
+pub struct Report {
+    day: String,
+    quiet: bool,
+    part: SolutionPart,
+    allow_trailing: bool,
+    format: OutputFormat,
+    time: bool,
+    mem: bool,
+    answers: Vec<(SolutionPart, String)>,
+    timings: Timings,
+    memory: Memory,
+    answers_file: PathBuf,
+    expected: Option<Answers>,
+    record: bool,
+    mismatches: Vec<SolutionPart>,
+}
+
+impl Report {
+    /// Creates the report for a run and loads the answers to check against
+    ///
+    /// With `--format json` an error is also printed as a JSON report, so
+    /// the output stays JSON.
+    ///
+    /// # Errors
+    ///
+    /// Check errors for [`Cli::expected_answers`], and `--mem` fails without
+    /// the counting allocator
+    pub fn new(day: impl Into<String>, cli: &Cli) -> AocResult<Self> {
+        let mut report = Self {
+            day: day.into(),
+            quiet: false,
+            part: cli.part(),
+            allow_trailing: cli.allow_trailing(),
+            format: cli.format(),
+            time: cli.time(),
+            mem: cli.mem(),
+            answers: Vec::new(),
+            timings: Timings::default(),
+            memory: Memory::default(),
+            answers_file: cli.answers_file(),
+            expected: None,
+            record: cli.record(),
+            mismatches: Vec::new(),
+        };
+        let expected = if cli.mem() && !COUNTING {
+            Err(anyhow!("--mem needs the count-alloc feature of utils, run with --features utils/count-alloc"))
+        } else {
+            cli.expected_answers()
+        };
+        match expected {
+            Ok(expected) => {
+                report.expected = expected;
+                Ok(report)
+            }
+            Err(error) => {
+                if report.format == OutputFormat::Json {
+                    println!("{}", report.to_json(Some(&error)));
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// Creates a report for running both parts that prints nothing, for
+    /// reading the answers and timings afterwards
+    #[must_use]
+    pub fn quiet(day: impl Into<String>) -> Self {
+        Self {
+            day: day.into(),
+            quiet: true,
+            part: SolutionPart::Both,
+            allow_trailing: false,
+            format: OutputFormat::default(),
+            time: false,
+            mem: false,
+            answers: Vec::new(),
+            timings: Timings::default(),
+            memory: Memory::default(),
+            answers_file: PathBuf::new(),
+            expected: None,
+            record: false,
+            mismatches: Vec::new(),
+        }
+    }
+
+    /// Checks the answers against `expected` as they come in
+    #[must_use]
+    pub fn expecting(mut self, expected: Answers) -> Self {
+        self.expected = Some(expected);
+        self
+    }
+
+    /// Answers recorded so far, see [`Answers::normalize`]
+    #[must_use]
+    pub fn answers(&self) -> &[(SolutionPart, String)] {
+        &self.answers
+    }
+
+    /// Time spent in each phase so far
+    #[must_use]
+    pub const fn timings(&self) -> &Timings {
+        &self.timings
+    }
+
+    /// Whether the answer to `part` matched the expected answer, if there
+    /// was both an answer and an expected answer
+    #[must_use]
+    pub fn verified(&self, part: SolutionPart) -> Option<bool> {
+        self.expected(part)?;
+        self.answers
+            .iter()
+            .any(|(answered, _)| *answered == part)
+            .then(|| !self.mismatches.contains(&part))
+    }
+
+    /// Determines if the part was requested
+    #[must_use]
+    pub fn should_run(&self, part: SolutionPart) -> bool {
+        self.part == SolutionPart::Both || self.part == part
+    }
+
+    /// Unwraps the parsed input, see [`finish_parse`]
+    ///
+    /// # Errors
+    ///
+    /// If the parser failed or left input unread without `--allow-trailing`
+    pub fn finish_parse<'a, T>(
+        &self,
+        input: &'a str,
+        parsed: ParseResult<'a, T>,
+    ) -> Result<T, ParseError> {
+        finish_parse(input, parsed, self.allow_trailing)
+    }
+
+    /// Runs `f` and records how long it took under `phase`, and its heap use
+    /// with `--mem`
+    ///
+    /// The timing is recorded outside of the memory measurement so that
+    /// growing the list of timings isn't counted as an allocation of the
+    /// solution.
+    pub fn measure<T>(&mut self, phase: Phase, f: impl FnOnce() -> T) -> T {
+        if self.mem {
+            let mut elapsed = Duration::ZERO;
+            let result = self.memory.measure(phase, || {
+                let start = Instant::now();
+                let result = f();
+                elapsed = start.elapsed();
+                result
+            });
+            self.timings.record(phase, elapsed);
+            result
+        } else {
+            self.timings.measure(phase, f)
+        }
+    }
+
+    /// Records the answer for a part and checks it against the expected
+    /// answer
+    ///
+    /// In text mode the answer is printed straight away so a slow part 2
+    /// doesn't hold back the answer to part 1.
+    pub fn answer(&mut self, part: SolutionPart, answer: &impl Debug) {
+        if !self.quiet && self.format == OutputFormat::Text {
+            println!("{part}: {answer:#?}");
+        }
+        let answer = Answers::normalize(answer);
+        if let Some(expected) = self.expected(part) {
+            if expected != answer {
+                if !self.quiet {
+                    eprintln!("{part} was expected to be {expected}");
+                }
+                self.mismatches.push(part);
+            }
+        }
+        self.answers.push((part, answer));
+    }
+
+    fn expected(&self, part: SolutionPart) -> Option<&str> {
+        self.expected
+            .as_ref()
+            .and_then(|expected| expected.get(part))
+    }
+
+    /// Saves or verifies the answers once all parts have run
+    fn settle(&self) -> AocResult<()> {
+        if self.record {
+            let mut answers = if self.answers_file.exists() {
+                Answers::load(&self.answers_file)?
+            } else {
+                Answers::default()
+            };
+            for (part, answer) in &self.answers {
+                answers.set(*part, answer.clone());
+            }
+            answers.save(&self.answers_file)?;
+            eprintln!("Recorded answers in {}", self.answers_file.display());
+        }
+        if self.mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "{} did not match the answers in {}",
+                self.mismatches
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" and "),
+                self.answers_file.display()
+            ))
+        }
+    }
+
+    /// Prints everything that hasn't been printed yet, then records or
+    /// verifies the answers
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `result` after it has been reported, or an
+    /// error if an answer didn't match the answers file
+    pub fn finish(self, result: AocResult<()>) -> AocResult<()> {
+        let result = result.and_then(|()| self.settle());
+        match self.format {
+            OutputFormat::Text => {
+                if self.time {
+                    println!("{}", self.timings);
+                }
+                if self.mem {
+                    println!("{}", self.memory);
+                }
+            }
+            OutputFormat::Json => println!("{}", self.to_json(result.as_ref().err())),
+        }
+        result
+    }
+
+    fn to_json(&self, error: Option<&anyhow::Error>) -> Value {
+        let answers: Map<String, Value> = self
+            .answers
+            .iter()
+            .map(|(part, answer)| (part.key().to_string(), json!(answer)))
+            .collect();
+        let mut timing: Map<String, Value> = self
+            .timings
+            .iter()
+            .map(|(phase, duration)| (phase.key().to_string(), json!(duration.as_nanos())))
+            .collect();
+        timing.insert("total".to_string(), json!(self.timings.total().as_nanos()));
+        let verified: Map<String, Value> = self
+            .answers
+            .iter()
+            .filter_map(|(part, _)| Some((part.key().to_string(), json!(self.verified(*part)?))))
+            .collect();
+        let mut report = json!({
+            "day": self.day,
+            "part": self.part.key(),
+            "answer": answers,
+            "verified": verified,
+            "timing": timing,
+            "error": error.map(|e| format!("{e:#}")),
+        });
+        if self.mem {
+            let memory: Map<String, Value> = self
+                .memory
+                .iter()
+                .map(|(phase, stats)| {
+                    (
+                        phase.key().to_string(),
+                        json!({
+                            "allocations": stats.allocations,
+                            "bytes": stats.bytes,
+                            "peak": stats.peak,
+                        }),
+                    )
+                })
+                .collect();
+            report["memory"] = json!(memory);
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::{env, fs};
+
+    fn expecting(part1: &str) -> Report {
+        Report::quiet("day-01").expecting(Answers {
+            part1: Some(part1.to_string()),
+            part2: None,
+        })
+    }
+
+    #[test]
+    fn test_string_answer() {
+        let mut report = expecting("4,6,3,5");
+        report.answer(SolutionPart::PartOne, &"4,6,3,5".to_string());
+        assert_eq!(report.verified(SolutionPart::PartOne), Some(true));
+        assert_eq!(
+            report.answers(),
+            [(SolutionPart::PartOne, "4,6,3,5".to_string())]
+        );
+        assert_eq!(report.to_json(None)["answer"]["part1"], "4,6,3,5");
+    }
+
+    #[test]
+    fn test_matching_answer() {
+        let mut report = expecting("11");
+        report.answer(SolutionPart::PartOne, &11);
+        assert_eq!(report.verified(SolutionPart::PartOne), Some(true));
+        assert!(report.settle().is_ok());
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let mut report = expecting("11");
+        report.answer(SolutionPart::PartOne, &12);
+        assert_eq!(report.verified(SolutionPart::PartOne), Some(false));
+        assert!(report.settle().is_err());
+    }
+
+    #[test]
+    fn test_missing_expected_answer() {
+        let mut report = expecting("11");
+        report.answer(SolutionPart::PartTwo, &31);
+        assert_eq!(report.verified(SolutionPart::PartTwo), None);
+        assert_eq!(report.verified(SolutionPart::PartOne), None);
+        assert!(report.settle().is_ok());
+    }
+
+    #[test]
+    fn test_record_merges() {
+        let path = env::temp_dir().join(format!("utils-record-{}.toml", std::process::id()));
+        fs::write(&path, "part1 = \"11\"\n").unwrap();
+        let expect = path.to_str().unwrap();
+        let cli = Cli::parse_from([
+            "day-01",
+            "input.txt",
+            "-p",
+            "2",
+            "--record",
+            "--expect",
+            expect,
+        ]);
+        let mut report = Report::new("day-01", &cli).unwrap();
+        report.answer(SolutionPart::PartTwo, &"ab".to_string());
+        let settled = report.settle();
+        let recorded = Answers::load(&path);
+        fs::remove_file(&path).unwrap();
+        settled.unwrap();
+        assert_eq!(
+            recorded.unwrap(),
+            Answers {
+                part1: Some("11".to_string()),
+                part2: Some("ab".to_string()),
+            }
+        );
+    }
+}
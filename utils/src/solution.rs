@@ -0,0 +1,99 @@
+use crate::{finish_parse, AocResult, ParseResult, Phase, Report, SolutionPart};
+use std::fmt::Debug;
+
+/// A day of Advent of Code
+///
+/// Implementing this lets a day be run from the command line with
+/// [`aoc_main!`](crate::aoc_main), or called, listed and benchmarked as a
+/// library through [`Day`].
+pub trait Solution {
+    /// Day of the event, starting at 1
+    const DAY: u8;
+
+    /// Puzzle input after parsing, shared by both parts
+    type Input;
+
+    /// Answer to part 1
+    type Part1: Debug;
+
+    /// Answer to part 2
+    type Part2: Debug;
+
+    /// Parses the puzzle input
+    ///
+    /// # Errors
+    ///
+    /// If the input isn't in the expected format
+    fn parse(input: &str) -> ParseResult<'_, Self::Input>;
+
+    /// Solves part 1
+    ///
+    /// # Errors
+    ///
+    /// If there is no answer for the input
+    fn part1(input: &Self::Input) -> AocResult<Self::Part1>;
+
+    /// Solves part 2
+    ///
+    /// # Errors
+    ///
+    /// If there is no answer for the input
+    fn part2(input: &Self::Input) -> AocResult<Self::Part2>;
+
+    /// Parses all of `input` and solves both parts
+    ///
+    /// # Errors
+    ///
+    /// If parsing fails, leaves input unread or either part fails
+    fn solve(input: &str) -> AocResult<(Self::Part1, Self::Part2)> {
+        let parsed = finish_parse(input, Self::parse(input), false)?;
+        Ok((Self::part1(&parsed)?, Self::part2(&parsed)?))
+    }
+}
+
+/// Runs the parts of `S` requested by `report` on `input`
+///
+/// # Errors
+///
+/// If parsing or a part fails
+pub fn run<S: Solution>(input: &str, report: &mut Report) -> AocResult<()> {
+    let parsed = report.measure(Phase::Parse, || S::parse(input));
+    let parsed = report.finish_parse(input, parsed)?;
+    if report.should_run(SolutionPart::PartOne) {
+        let part1 = report.measure(Phase::PartOne, || S::part1(&parsed))?;
+        report.answer(SolutionPart::PartOne, &part1);
+    }
+    if report.should_run(SolutionPart::PartTwo) {
+        let part2 = report.measure(Phase::PartTwo, || S::part2(&parsed))?;
+        report.answer(SolutionPart::PartTwo, &part2);
+    }
+    Ok(())
+}
+
+/// A [`Solution`] with its types erased, so days can be kept in a registry
+/// and run generically
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    /// Day of the event, starting at 1
+    pub number: u8,
+    run: fn(&str, &mut Report) -> AocResult<()>,
+}
+
+impl Day {
+    #[must_use]
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            run: run::<S>,
+        }
+    }
+
+    /// Runs the day on `input`, see [`run`]
+    ///
+    /// # Errors
+    ///
+    /// If parsing or a part fails
+    pub fn run(&self, input: &str, report: &mut Report) -> AocResult<()> {
+        (self.run)(input, report)
+    }
+}
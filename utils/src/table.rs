@@ -0,0 +1,32 @@
+/// Prints rows of cells as left-aligned columns under a header
+pub fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let widths: Vec<usize> = (0..N)
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([header[i].chars().count()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let print_row = |row: [&str; N]| {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    };
+    print_row(header);
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("  ")
+    );
+    for row in rows {
+        print_row(row.each_ref().map(String::as_str));
+    }
+}
@@ -0,0 +1,90 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// A measured step of running a solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    ParseOne,
+    ParseTwo,
+    PartOne,
+    PartTwo,
+}
+
+impl Phase {
+    /// Name used for the phase in machine-readable output
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::ParseOne => "parse1",
+            Self::ParseTwo => "parse2",
+            Self::PartOne => "part1",
+            Self::PartTwo => "part2",
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "Parse"),
+            Self::ParseOne => write!(f, "Parse 1"),
+            Self::ParseTwo => write!(f, "Parse 2"),
+            Self::PartOne => write!(f, "Part 1"),
+            Self::PartTwo => write!(f, "Part 2"),
+        }
+    }
+}
+
+/// Wall clock time spent in each phase of a solution
+#[derive(Debug, Default, Clone)]
+pub struct Timings {
+    phases: Vec<(Phase, Duration)>,
+}
+
+impl Timings {
+    /// Runs `f` and records how long it took under `phase`
+    pub fn measure<T>(&mut self, phase: Phase, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.record(phase, start.elapsed());
+        result
+    }
+
+    /// Records `duration` under `phase`, for phases timed by the caller
+    pub fn record(&mut self, phase: Phase, duration: Duration) {
+        self.phases.push((phase, duration));
+    }
+
+    /// Time recorded for `phase`, if it ran
+    #[must_use]
+    pub fn get(&self, phase: Phase) -> Option<Duration> {
+        self.phases
+            .iter()
+            .find(|(name, _)| *name == phase)
+            .map(|(_, duration)| *duration)
+    }
+
+    /// Recorded phases in the order they ran
+    pub fn iter(&self) -> impl Iterator<Item = (Phase, Duration)> + '_ {
+        self.phases.iter().copied()
+    }
+
+    /// Sum of all recorded phases
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (phase, duration) in &self.phases {
+            write!(f, "{phase}: {duration:.2?} | ")?;
+        }
+        write!(f, "Total: {:.2?}", self.total())
+    }
+}
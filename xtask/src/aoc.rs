@@ -0,0 +1,319 @@
+use crate::{session::read_session, workspace::Workspace};
+use reqwest::blocking as req;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const AOC_URL: &str = "https://adventofcode.com";
+
+/// The requests made to the Advent of Code website
+///
+/// Kept behind a trait so the client can be pointed at a stand-in server.
+pub trait Http {
+    /// Fetches `url`, failing on an error status
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<String>;
+
+    /// Posts `form` url-encoded to `url`, failing on an error status
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> anyhow::Result<String>;
+}
+
+/// [`Http`] through a blocking reqwest client
+#[derive(Debug, Default)]
+pub struct Reqwest {
+    client: req::Client,
+}
+
+impl Http for Reqwest {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<String> {
+        Ok(self
+            .client
+            .get(url)
+            .header(reqwest::header::COOKIE, format!("session={session}"))
+            .send()?
+            .error_for_status()?
+            .text()?)
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        Ok(self
+            .client
+            .post(url)
+            .header(reqwest::header::COOKIE, format!("session={session}"))
+            .form(form)
+            .send()?
+            .error_for_status()?
+            .text()?)
+    }
+}
+
+/// What the website said about a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Answered too recently, with how long is left to wait if it was given
+    RateLimited(Option<String>),
+    /// The part is already solved or not unlocked yet
+    WrongLevel,
+    /// A response that isn't understood, as text
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the verdict from the page returned after submitting
+    #[must_use]
+    pub fn parse(page: &str) -> Self {
+        let message = page
+            .split_once("<article>")
+            .and_then(|(_, article)| article.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+        if message.contains("That's the right answer") {
+            Self::Right
+        } else if message.contains("That's not the right answer") {
+            if message.contains("your answer is too high") {
+                Self::TooHigh
+            } else if message.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Self::RateLimited(
+                message
+                    .split_once("You have ")
+                    .and_then(|(_, left)| left.split_once(" left to wait"))
+                    .map(|(wait, _)| wait.to_string()),
+            )
+        } else if message.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            let mut text = String::new();
+            let mut rest = message;
+            while let Some((before, tag)) = rest.split_once('<') {
+                text.push_str(before);
+                rest = tag.split_once('>').map_or("", |(_, after)| after);
+            }
+            text.push_str(rest);
+            Self::Unknown(text.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+    }
+
+    /// Determines if the answer is known to be wrong
+    #[must_use]
+    pub const fn is_wrong(&self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Right => write!(f, "That's the right answer"),
+            Self::Wrong => write!(f, "That's not the right answer"),
+            Self::TooHigh => write!(f, "That's not the right answer, it's too high"),
+            Self::TooLow => write!(f, "That's not the right answer, it's too low"),
+            Self::RateLimited(Some(wait)) => {
+                write!(f, "Answered too recently, {wait} left to wait")
+            }
+            Self::RateLimited(None) => write!(f, "Answered too recently"),
+            Self::WrongLevel => write!(f, "That part is already solved or still locked"),
+            Self::Unknown(text) => write!(f, "Unexpected response: {text}"),
+        }
+    }
+}
+
+/// Talks to the Advent of Code website for a year of puzzles
+#[derive(Debug)]
+pub struct AocClient<H> {
+    http: H,
+    base_url: String,
+    year: u16,
+    session: String,
+}
+
+impl AocClient<Reqwest> {
+    /// A client for the puzzles of the workspace's year on adventofcode.com
+    /// using the session key of its profile, telling where the key comes from
+    pub fn for_workspace(workspace: &Workspace) -> anyhow::Result<Self> {
+        let (session, source) = read_session(workspace.profile())?;
+        println!("Using the session key from {source}");
+        Ok(Self::with_session(workspace, session))
+    }
+
+    /// A client for the puzzles of the workspace's year on adventofcode.com
+    /// using a session key
+    pub fn with_session(workspace: &Workspace, session: String) -> Self {
+        Self::new(Reqwest::default(), workspace.year(), session)
+    }
+}
+
+impl<H: Http> AocClient<H> {
+    pub fn new(http: H, year: u16, session: String) -> Self {
+        Self {
+            http,
+            base_url: AOC_URL.to_string(),
+            year,
+            session,
+        }
+    }
+
+    /// Sends the requests to a stand-in server for tests
+    #[cfg(test)]
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    fn day_url(&self, day: u64) -> String {
+        format!("{}/{}/day/{day}", self.base_url, self.year)
+    }
+
+    /// Name of the user the session key is logged in as, if it is
+    ///
+    /// This only fetches the calendar of the year, which any user can see.
+    pub fn user(&self) -> anyhow::Result<Option<String>> {
+        let page = self
+            .http
+            .get(&format!("{}/{}", self.base_url, self.year), &self.session)?;
+        if !page.contains("[Log Out]") {
+            return Ok(None);
+        }
+        let user = page
+            .split_once("<div class=\"user\">")
+            .and_then(|(_, user)| user.split(['<', '\n']).next())
+            .map_or("an unnamed user", str::trim);
+        Ok(Some(user.to_string()))
+    }
+
+    /// Downloads the puzzle input of a day
+    pub fn input(&self, day: u64) -> anyhow::Result<String> {
+        self.http
+            .get(&format!("{}/input", self.day_url(day)), &self.session)
+    }
+
+    /// Submits the answer to a part of a day
+    pub fn submit(&self, day: u64, part: u8, answer: &str) -> anyhow::Result<Verdict> {
+        let page = self.http.post_form(
+            &format!("{}/answer", self.day_url(day)),
+            &self.session,
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        Ok(Verdict::parse(&page))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    /// Serves a single request with `response`, returning what was requested
+    fn stand_in(response: String) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .unwrap();
+            request
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn test_verdict() {
+        let verdict = |message| Verdict::parse(&page(message));
+        assert_eq!(
+            verdict("That's the right answer! You are one gold star closer."),
+            Verdict::Right
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(verdict("That's not the right answer."), Verdict::Wrong);
+        assert_eq!(
+            verdict("You gave an answer too recently. You have 38s left to wait."),
+            Verdict::RateLimited(Some("38s".to_string()))
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level."),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            verdict("Something <em>else</em>"),
+            Verdict::Unknown("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, server) = stand_in(page("That's not the right answer; your answer is too low."));
+        let client = AocClient::new(Reqwest::default(), 2024, "abc".to_string()).with_base_url(url);
+        assert_eq!(client.submit(6, 2, "41").unwrap(), Verdict::TooLow);
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/6/answer "));
+        assert!(request.contains("cookie: session=abc\r\n"));
+        assert!(request.ends_with("level=2&answer=41"));
+    }
+
+    #[test]
+    fn test_user() {
+        let (url, server) = stand_in(
+            "<header><div class=\"user\">Santa <span class=\"star-count\">18*</span></div>\
+             <a href=\"/2024/auth/logout\">[Log Out]</a></header>"
+                .to_string(),
+        );
+        let client = AocClient::new(Reqwest::default(), 2024, "abc".to_string()).with_base_url(url);
+        assert_eq!(client.user().unwrap(), Some("Santa".to_string()));
+        assert!(server.join().unwrap().starts_with("GET /2024 "));
+
+        let (url, _) = stand_in("<a href=\"/2024/auth/login\">[Log In]</a>".to_string());
+        let client = AocClient::new(Reqwest::default(), 2024, "abc".to_string()).with_base_url(url);
+        assert_eq!(client.user().unwrap(), None);
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, server) = stand_in("1 2\n3 4\n".to_string());
+        let client = AocClient::new(Reqwest::default(), 2024, "abc".to_string()).with_base_url(url);
+        assert_eq!(client.input(1).unwrap(), "1 2\n3 4\n");
+        assert!(server.join().unwrap().starts_with("GET /2024/day/1/input "));
+    }
+}
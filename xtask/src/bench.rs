@@ -0,0 +1,368 @@
+use crate::workspace::Workspace;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use xshell::{cmd, Shell};
+
+/// Where the results of every benchmark are kept, relative to the workspace
+pub const HISTORY_FILE: &str = "bench/history.json";
+
+/// Folder of the named baselines, relative to the workspace
+const BASELINE_FOLDER: &str = "bench/baselines";
+
+/// Median, fastest and slowest time of a phase, in nanoseconds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub median: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+impl Stats {
+    /// Summarises the samples, if there are any
+    fn of(mut samples: Vec<u64>) -> Option<Self> {
+        samples.sort_unstable();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples.get(middle.checked_sub(1)?)? + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        Some(Self {
+            median,
+            min: *samples.first()?,
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Timings of each phase of a day, keyed by phase (`parse`, `part1`, ...)
+pub type DayStats = BTreeMap<String, Stats>;
+
+/// A single `xtask bench` invocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    /// Short hash of `HEAD` when the benchmark ran
+    pub commit: Option<String>,
+    /// Number of times each day was run
+    pub runs: usize,
+    /// Results keyed by package name
+    pub days: BTreeMap<String, DayStats>,
+}
+
+/// Runs the days repeatedly on their `input.txt`, prints the timings and
+/// appends them to the history file
+pub fn bench(
+    sh: &Shell,
+    workspace: &Workspace,
+    day: Option<u64>,
+    runs: usize,
+) -> anyhow::Result<BenchRun> {
+    if runs == 0 {
+        bail!("Need at least one run to benchmark");
+    }
+    let mut days = workspace.days();
+    days.retain(|(number, _)| day.is_none_or(|day| day == *number));
+    days.retain(|(number, package)| {
+        let exists = workspace.input(*number).exists();
+        if !exists {
+            println!("{package}: no input.txt, skipping");
+        }
+        exists
+    });
+    if days.is_empty() {
+        bail!("No day to benchmark");
+    }
+
+    let args: Vec<_> = days.iter().flat_map(|(_, p)| ["-p", p.as_str()]).collect();
+    cmd!(sh, "cargo build -q --release {args...}").run()?;
+
+    let release = workspace
+        .metadata()
+        .target_directory
+        .as_std_path()
+        .join("release");
+    let mut results = BTreeMap::new();
+    for (day, package) in &days {
+        let stats = bench_day(sh, &release.join(package), &workspace.input(*day), runs)
+            .with_context(|| format!("Benchmarking {package}"))?;
+        print_day(package, &stats);
+        results.insert(package.clone(), stats);
+    }
+
+    let run = BenchRun {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs()),
+        commit: cmd!(sh, "git rev-parse --short HEAD")
+            .quiet()
+            .ignore_stderr()
+            .read()
+            .ok(),
+        runs,
+        days: results,
+    };
+    let history = workspace.root().join(HISTORY_FILE);
+    append_history(&history, &run)?;
+    println!("Saved results to {}", history.display());
+    Ok(run)
+}
+
+/// Runs a day's binary `runs` times and summarises the timings it reports
+fn bench_day(sh: &Shell, binary: &Path, input: &Path, runs: usize) -> anyhow::Result<DayStats> {
+    let mut samples: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    for _ in 0..runs {
+        let output = cmd!(sh, "{binary} {input} --format json").quiet().read()?;
+        let report: Value = serde_json::from_str(&output)?;
+        let timing = report["timing"]
+            .as_object()
+            .context("No timings in the output")?;
+        for (phase, nanos) in timing {
+            let nanos = nanos.as_u64().context("Timing isn't a number")?;
+            samples.entry(phase.clone()).or_default().push(nanos);
+        }
+    }
+    Ok(samples
+        .into_iter()
+        .filter_map(|(phase, samples)| Some((phase, Stats::of(samples)?)))
+        .collect())
+}
+
+fn print_day(package: &str, stats: &DayStats) {
+    println!("{package}");
+    for (phase, stats) in stats {
+        let time = |nanos| format!("{:.2?}", Duration::from_nanos(nanos));
+        println!(
+            "  {phase:<6} median {:>10}  min {:>10}  max {:>10}",
+            time(stats.median),
+            time(stats.min),
+            time(stats.max)
+        );
+    }
+}
+
+/// Reads every benchmark saved so far, oldest first
+pub fn load_history(path: &Path) -> anyhow::Result<Vec<BenchRun>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let history = fs::read_to_string(path)?;
+    serde_json::from_str(&history).with_context(|| format!("Reading {}", path.display()))
+}
+
+fn append_history(path: &Path, run: &BenchRun) -> anyhow::Result<()> {
+    let mut history = load_history(path)?;
+    history.push(run.clone());
+    write_json(path, &history)
+}
+
+fn write_json(path: &Path, value: &impl Serialize) -> anyhow::Result<()> {
+    if let Some(folder) = path.parent() {
+        fs::create_dir_all(folder)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(value)? + "\n")?;
+    Ok(())
+}
+
+fn baseline_file(workspace: &Workspace, name: &str) -> PathBuf {
+    workspace
+        .root()
+        .join(BASELINE_FOLDER)
+        .join(format!("{name}.json"))
+}
+
+/// Saves the benchmark as the baseline called `name`, replacing the timings
+/// of the days it ran and keeping those of the others
+pub fn save_baseline(workspace: &Workspace, name: &str, run: &BenchRun) -> anyhow::Result<()> {
+    let path = baseline_file(workspace, name);
+    let mut baseline = if path.exists() {
+        load_baseline(&path)?
+    } else {
+        BenchRun {
+            days: BTreeMap::new(),
+            ..run.clone()
+        }
+    };
+    baseline.timestamp = run.timestamp;
+    baseline.commit.clone_from(&run.commit);
+    baseline.runs = run.runs;
+    baseline.days.extend(
+        run.days
+            .iter()
+            .map(|(day, stats)| (day.clone(), stats.clone())),
+    );
+    write_json(&path, &baseline)?;
+    println!("Saved baseline {name} to {}", path.display());
+    Ok(())
+}
+
+fn load_baseline(path: &Path) -> anyhow::Result<BenchRun> {
+    serde_json::from_str(&fs::read_to_string(path)?)
+        .with_context(|| format!("Reading {}", path.display()))
+}
+
+/// A phase whose median got slower than the baseline allows
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: String,
+    pub phase: String,
+    pub baseline: u64,
+    pub current: u64,
+}
+
+impl Regression {
+    /// How much slower the phase got, in percent
+    #[allow(clippy::cast_precision_loss)]
+    fn slowdown(&self) -> f64 {
+        // Dividing last keeps a slowdown of exactly the threshold exact
+        (self.current as f64 - self.baseline as f64) * 100.0 / self.baseline as f64
+    }
+}
+
+/// Phases of `run` that are more than `threshold` percent slower than in
+/// `baseline`
+///
+/// The total is left out as it only adds up the other phases. Days and phases
+/// missing from the baseline can't regress, nor can phases that took 0 ns in
+/// the baseline, as they were too fast to measure.
+fn regressions(baseline: &BenchRun, run: &BenchRun, threshold: f64) -> Vec<Regression> {
+    run.days
+        .iter()
+        .filter_map(|(day, stats)| Some((day, stats, baseline.days.get(day)?)))
+        .flat_map(|(day, stats, before)| {
+            stats
+                .iter()
+                .filter(|(phase, _)| *phase != "total")
+                .filter_map(move |(phase, stats)| {
+                    Some(Regression {
+                        day: day.clone(),
+                        phase: phase.clone(),
+                        baseline: before.get(phase).filter(|before| before.median > 0)?.median,
+                        current: stats.median,
+                    })
+                })
+        })
+        .filter(|regression| regression.slowdown() > threshold)
+        .collect()
+}
+
+/// Compares the benchmark with the baseline called `name`
+///
+/// # Errors
+///
+/// If there is no such baseline or any phase is more than `threshold`
+/// percent slower than in it
+pub fn compare(
+    workspace: &Workspace,
+    name: &str,
+    run: &BenchRun,
+    threshold: f64,
+) -> anyhow::Result<()> {
+    let path = baseline_file(workspace, name);
+    if !path.exists() {
+        bail!("There is no baseline {name}, save one with --save-baseline {name}");
+    }
+    let baseline = load_baseline(&path)?;
+    for day in run
+        .days
+        .keys()
+        .filter(|day| !baseline.days.contains_key(*day))
+    {
+        println!("{day}: not in baseline {name}");
+    }
+    let regressions = regressions(&baseline, run, threshold);
+    if regressions.is_empty() {
+        println!("No phase is more than {threshold}% slower than baseline {name}");
+        return Ok(());
+    }
+    let time = |nanos| format!("{:.2?}", Duration::from_nanos(nanos));
+    for regression in &regressions {
+        println!(
+            "{} {}: {} -> {} ({:+.1}%)",
+            regression.day,
+            regression.phase,
+            time(regression.baseline),
+            time(regression.current),
+            regression.slowdown()
+        );
+    }
+    bail!(
+        "{} phases are more than {threshold}% slower than baseline {name}",
+        regressions.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(days: &[(&str, &[(&str, u64)])]) -> BenchRun {
+        BenchRun {
+            timestamp: 0,
+            commit: None,
+            runs: 1,
+            days: days
+                .iter()
+                .map(|(day, phases)| {
+                    let stats = phases
+                        .iter()
+                        .map(|(phase, median)| {
+                            let stats = Stats {
+                                median: *median,
+                                min: *median,
+                                max: *median,
+                            };
+                            ((*phase).to_string(), stats)
+                        })
+                        .collect();
+                    ((*day).to_string(), stats)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_stats_median() {
+        let odd = Stats::of(vec![30, 10, 20]).unwrap();
+        assert_eq!((odd.median, odd.min, odd.max), (20, 10, 30));
+        let even = Stats::of(vec![40, 10, 30, 20]).unwrap();
+        assert_eq!((even.median, even.min, even.max), (25, 10, 40));
+        assert_eq!(Stats::of(vec![7]).unwrap().median, 7);
+        assert!(Stats::of(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_regressions_threshold() {
+        let baseline = run(&[("day-01", &[("parse", 100), ("part1", 100), ("total", 200)])]);
+        let at_threshold = run(&[("day-01", &[("parse", 110), ("part1", 100), ("total", 210)])]);
+        assert!(regressions(&baseline, &at_threshold, 10.0).is_empty());
+        let above = run(&[("day-01", &[("parse", 111), ("part1", 100), ("total", 211)])]);
+        assert_eq!(
+            regressions(&baseline, &above, 10.0),
+            [Regression {
+                day: "day-01".to_string(),
+                phase: "parse".to_string(),
+                baseline: 100,
+                current: 111,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_regressions_skip_unmeasured() {
+        let baseline = run(&[("day-01", &[("parse", 0)])]);
+        let current = run(&[
+            ("day-01", &[("parse", 50), ("part1", 50)]),
+            ("day-02", &[("parse", 50)]),
+        ]);
+        assert!(regressions(&baseline, &current, 10.0).is_empty());
+    }
+}
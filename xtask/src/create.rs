@@ -1,16 +1,12 @@
-use anyhow::Context;
-use cargo_metadata::Metadata;
-use indoc::{formatdoc, indoc};
+use crate::{aoc::AocClient, workspace::Workspace};
+use anyhow::{bail, Context};
 use log::debug;
-use reqwest::blocking as req;
 use std::{
-    fs::{self, read_to_string, File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::{self, Write},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
-const AOC_YEAR: &str = "2024";
-
 /// Only create file if path doesn't exist
 fn create_new<P: AsRef<Path>>(path: P) -> io::Result<File> {
     OpenOptions::new()
@@ -20,119 +16,271 @@ fn create_new<P: AsRef<Path>>(path: P) -> io::Result<File> {
         .open(path.as_ref())
 }
 
+/// Folder of the day templates, relative to the workspace
+const TEMPLATE_FOLDER: &str = "templates";
+
+/// Replaces the `{{name}}` placeholders of a template file
+///
+/// | Placeholder      | Example     |
+/// | ---------------- | ----------- |
+/// | `{{day}}`        | `6`         |
+/// | `{{day_padded}}` | `06`        |
+/// | `{{year}}`       | `2024`      |
+/// | `{{package}}`    | `day-06`    |
+/// | `{{crate}}`      | `day_06`    |
+fn fill(template: &str, placeholders: &[(&str, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{{{name}}}}}"), value)
+        })
+}
+
+/// Values of the placeholders for the package of a day, see [`fill`]
+fn placeholders(day: u64, year: u16, package: String) -> [(&'static str, String); 5] {
+    [
+        ("day", day.to_string()),
+        ("day_padded", format!("{day:0>2}")),
+        ("year", year.to_string()),
+        ("crate", package.replace('-', "_")),
+        ("package", package),
+    ]
+}
+
+/// Every file below `folder`, relative to it
+fn template_files(folder: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(folder)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            let nested = template_files(&path)?;
+            let prefix = path.strip_prefix(folder).unwrap_or(&path).to_path_buf();
+            files.extend(nested.into_iter().map(|file| prefix.join(file)));
+        } else {
+            files.push(path.strip_prefix(folder).unwrap_or(&path).to_path_buf());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
 /// Generates the files for the new day
 ///
-/// Scaffolds the project files for the new day of Advent of Code.
-/// Then, we try to download the input file using the session key
-/// in the `~/.adventofcode` file.
-pub fn generate_day(day: u64, metadata: &Metadata) -> anyhow::Result<()> {
-    let day_folder = format!("day-{day:0>2}");
-    let location = metadata.workspace_root.as_std_path().join(day_folder);
+/// Scaffolds the project files for the new day of Advent of Code from the
+/// `templates/<template>` folder of the workspace, keeping the files that
+/// already exist. Then, we try to download the input file using the session
+/// key of the profile, see [`read_session`](crate::session::read_session).
+pub fn generate_day(day: u64, template: &str, workspace: &Workspace) -> anyhow::Result<()> {
+    let templates = workspace.root().join(TEMPLATE_FOLDER);
+    let folder = templates.join(template);
+    if !folder.is_dir() {
+        let mut known: Vec<_> = fs::read_dir(&templates)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| Some(entry.ok()?.file_name().to_string_lossy().into_owned()))
+            .collect();
+        known.sort();
+        bail!(
+            "No template {template} in {}, there is {}",
+            templates.display(),
+            known.join(", ")
+        );
+    }
+    let placeholders = placeholders(day, workspace.year(), workspace.package(day));
+    let location = workspace.day_folder(day);
     debug!("New folder location: {}", location.display());
-    fs::create_dir_all(location.join("src"))?;
-    if let Ok(mut file) = create_new(location.join("Cargo.toml")) {
-        println!("Creating Cargo.toml");
-        file.write_all(
-            formatdoc! { r#"
-[package]
-name = "day-{day:0>2}"
-version = "1.0.0"
-edition = "2021"
-
-# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
-
-[dependencies]
-itertools.workspace = true
-nom.workspace = true
-utils = {{ path = "../utils", version = "*" }}
-
-"# }
-            .as_bytes(),
-        )?;
+    for file in template_files(&folder)? {
+        let target = location.join(&file);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        if let Ok(mut created) = create_new(&target) {
+            println!("Creating {}", file.display());
+            let template = fs::read_to_string(folder.join(&file))?;
+            created.write_all(fill(&template, &placeholders).as_bytes())?;
+        } else {
+            println!("{} exists", file.display());
+        }
+    }
+    if workspace.namespaced() {
+        add_year_to_members(workspace)?;
+    }
+    add_to_runner(workspace, day)?;
+    if location.join("input.txt").exists() {
+        println!("input.txt exists");
     } else {
-        println!("Cargo.toml exists");
+        generate_input(workspace, day, &location.join("input.txt"))?;
     }
-    if let Ok(mut file) = create_new(location.join("src").join("main.rs")) {
-        println!("Creating main.rs");
-        file.write_all(
-            indoc! { r#"
-use itertools::Itertools;
-#[allow(clippy::wildcard_imports)]
-use utils::*;
-
-#[derive(Debug, PartialEq)]
-struct InputData {}
-
-fn parse(input: &str) -> ParseResult<InputData> {
-    todo!()
+    Ok(())
 }
 
-#[allow(clippy::unnecessary_wraps)]
-fn part1(input: &InputData) -> AocResult<()> {
+/// Adds the `YYYY/day-*` folders of the year to the workspace members of the
+/// root `Cargo.toml`, unless they're already there
+///
+/// Cargo refuses a member pattern that matches nothing, so it can only be added
+/// once the first day of the year exists.
+fn add_year_to_members(workspace: &Workspace) -> anyhow::Result<()> {
+    let manifest = workspace.root().join("Cargo.toml");
+    let content = fs::read_to_string(&manifest)?;
+    let member = format!("\"{}/day-*\"", workspace.year());
+    if content.contains(&member) {
+        return Ok(());
+    }
+    let (before, after) = content
+        .split_once("members = [")
+        .context("No workspace members in Cargo.toml")?;
+    println!("Adding {member} to the workspace members");
+    fs::write(&manifest, format!("{before}members = [{member}, {after}"))?;
     Ok(())
 }
 
-#[allow(clippy::unnecessary_wraps)]
-fn part2(input: &InputData) -> AocResult<()> {
+/// Inserts the dependency line of a day package among the `day-*`
+/// dependencies of a manifest, keeping them sorted
+///
+/// Returns `None` when the package is already a dependency.
+fn insert_day_dependency(manifest: &str, package: &str, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let dependencies = lines.iter().position(|l| l.trim() == "[dependencies]")?;
+    let mut position = dependencies + 1;
+    for (i, existing) in lines.iter().enumerate().skip(dependencies + 1) {
+        if existing.starts_with('[') {
+            break;
+        }
+        let Some(name) = existing.split([' ', '=', '.']).next() else {
+            continue;
+        };
+        if name == package {
+            return None;
+        }
+        if name.starts_with("day-") && name < package {
+            position = i + 1;
+        }
+    }
+    lines.insert(position, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the package of a day to the dependencies of the runner, which
+/// generates its registry of days from them
+fn add_to_runner(workspace: &Workspace, day: u64) -> anyhow::Result<()> {
+    let manifest = workspace.root().join("runner").join("Cargo.toml");
+    let content = fs::read_to_string(&manifest)
+        .with_context(|| format!("Could not read {}", manifest.display()))?;
+    let package = workspace.package(day);
+    let folder = workspace.day_folder(day);
+    let relative = folder.strip_prefix(workspace.root()).unwrap_or(&folder);
+    let path = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let line = format!("{package} = {{ path = \"../{path}\" }}");
+    if let Some(content) = insert_day_dependency(&content, &package, &line) {
+        println!("Adding {package} to the runner");
+        fs::write(&manifest, content)?;
+    }
     Ok(())
 }
 
-aoc_main!(parse, part1, part2);
+pub fn generate_input(workspace: &Workspace, day: u64, location: &Path) -> anyhow::Result<()> {
+    println!("Retrieving input.txt");
+    let input_data = AocClient::for_workspace(workspace)?.input(day)?;
+    fs::write(location, input_data)?;
+    Ok(())
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{env, process::Command};
 
-    const INPUT: &str = "";
-
+    /// Creates a day from every template in a scratch workspace, next to
+    /// the target folder so its builds are kept, and checks that clippy has
+    /// nothing to say about them
     #[test]
-    fn test_parser() {
-        assert_parser!(parse, INPUT, InputData {});
-    }
+    fn test_templates_pass_clippy() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let scratch = root.join("target").join("template-check");
+        let mut manifest: toml::Table = fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .parse()
+            .unwrap();
+        let workspace = manifest["workspace"].as_table_mut().unwrap();
+        workspace.insert("members".to_string(), toml::Value::from(vec!["day-*"]));
+        workspace["dependencies"]["utils"]["path"] =
+            toml::Value::from(root.join("utils").to_string_lossy().into_owned());
+        fs::create_dir_all(&scratch).unwrap();
+        fs::write(scratch.join("Cargo.toml"), manifest.to_string()).unwrap();
+        if let Ok(lock) = fs::read(root.join("Cargo.lock")) {
+            fs::write(scratch.join("Cargo.lock"), lock).unwrap();
+        }
 
-    #[test]
-    fn test_part1() {
-        // assert_part!(parse, part1, INPUT, ());
+        let templates = root.join(TEMPLATE_FOLDER);
+        let mut names: Vec<_> = fs::read_dir(&templates)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        names.sort();
+        for (day, name) in (1..).zip(&names) {
+            let package = format!("day-{day:0>2}");
+            let location = scratch.join(&package);
+            let _ = fs::remove_dir_all(&location);
+            let placeholders = placeholders(day, 2024, package);
+            let folder = templates.join(name);
+            for file in template_files(&folder).unwrap() {
+                let target = location.join(&file);
+                fs::create_dir_all(target.parent().unwrap()).unwrap();
+                let template = fs::read_to_string(folder.join(&file)).unwrap();
+                fs::write(target, fill(&template, &placeholders)).unwrap();
+            }
+        }
+
+        let output = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+            .args(["clippy", "--quiet", "--all-targets", "--manifest-path"])
+            .arg(scratch.join("Cargo.toml"))
+            .args(["--", "-D", "warnings"])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "Templates {names:?} don't pass clippy:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
     }
 
     #[test]
-    fn test_part2() {
-        // assert_part!(parse, part2, INPUT, ());
-    }
-}
-"# }
-            .as_bytes(),
-        )?;
-    } else {
-        println!("main.rs exists");
-    }
-    if location.join("input.txt").exists() {
-        println!("input.txt exists");
-    } else {
-        generate_input(day, &location.join("input.txt"))?;
-    }
-    Ok(())
-}
+    fn test_insert_day_dependency() {
+        let manifest = "[dependencies]
+anyhow = \"1.0.66\"
+day-01 = { path = \"../day-01\" }
+day-03 = { path = \"../day-03\" }
+utils.workspace = true
 
-pub fn generate_input(day: u64, location: &Path) -> anyhow::Result<()> {
-    println!("Retrieving input.txt");
-    let aoc_session = read_to_string(
-        dirs::home_dir()
-            .context("No home directory")?
-            .join(".adventofcode"),
-    )?
-    .trim()
-    .to_string();
-    let client = req::Client::new();
-    let input_data = client
-        .request(
-            reqwest::Method::GET,
-            format!("https://adventofcode.com/{AOC_YEAR}/day/{day}/input"),
+[build-dependencies]
+toml = \"0.8.19\"
+";
+        assert_eq!(
+            insert_day_dependency(manifest, "day-02", "day-02 = { path = \"../day-02\" }").unwrap(),
+            "[dependencies]
+anyhow = \"1.0.66\"
+day-01 = { path = \"../day-01\" }
+day-02 = { path = \"../day-02\" }
+day-03 = { path = \"../day-03\" }
+utils.workspace = true
+
+[build-dependencies]
+toml = \"0.8.19\"
+"
+        );
+        assert!(insert_day_dependency(manifest, "day-03", "").is_none());
+        let with_year = insert_day_dependency(
+            manifest,
+            "day-2023-01",
+            "day-2023-01 = { path = \"../2023/day-01\" }",
         )
-        .header(reqwest::header::COOKIE, format!("session={aoc_session}"))
-        .send()?
-        .error_for_status()?
-        .text()?;
-    fs::write(location, input_data)?;
-    Ok(())
+        .unwrap();
+        assert!(with_year.contains(
+            "day-03 = { path = \"../day-03\" }\nday-2023-01 = { path = \"../2023/day-01\" }\n"
+        ));
+    }
 }
@@ -0,0 +1,228 @@
+use crate::workspace::Workspace;
+use anyhow::{bail, Context};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use utils::Answers;
+
+/// An example input from the puzzle page with the answers it should give
+#[derive(Debug, Default, PartialEq)]
+struct Example {
+    input: String,
+    answers: Answers,
+}
+
+/// Text between each `start` and the following `end`
+fn between<'a>(text: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    text.split(start)
+        .skip(1)
+        .filter_map(move |part| part.split_once(end).map(|(inside, _)| inside))
+}
+
+/// Removes the tags and decodes the entities of an HTML fragment
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some((before, tag)) = rest.split_once('<') {
+        text.push_str(before);
+        rest = tag.split_once('>').map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Finds the examples of a saved puzzle page
+///
+/// Each part of the puzzle is an `<article class="day-desc">`. The first
+/// `<pre><code>` block of a part is taken as its example, as later ones tend
+/// to show the steps of solving it. The last `<code><em>` of a part is its
+/// answer, which belongs to the part's own example or, when it has none, to
+/// the example before it.
+fn parse_page(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+    let articles = between(html, "<article class=\"day-desc\">", "</article>");
+    for (part, article) in articles.enumerate() {
+        if let Some(input) = between(article, "<pre><code>", "</code></pre>").next() {
+            let input = text(input);
+            if examples.last().is_none_or(|example| example.input != input) {
+                examples.push(Example {
+                    input,
+                    answers: Answers::default(),
+                });
+            }
+        }
+        let answer = between(article, "<code><em>", "</em></code>").last();
+        if let (Some(example), Some(answer)) = (examples.last_mut(), answer) {
+            let answer = Some(Answers::normalize(&text(answer).trim()));
+            match part {
+                0 => example.answers.part1 = answer,
+                1 => example.answers.part2 = answer,
+                _ => (),
+            }
+        }
+    }
+    examples
+}
+
+/// Writes the examples of a saved puzzle page into the day's `examples`
+/// folder as `example-N.txt`, with the answers in `example-N.expected`
+///
+/// The answers use the format of `answers.toml`, normalized like the answers
+/// of a run, so an example can be checked with `--expect`.
+pub fn extract_examples(day: u64, html: &Path, workspace: &Workspace) -> anyhow::Result<()> {
+    let page =
+        fs::read_to_string(html).with_context(|| format!("Could not read {}", html.display()))?;
+    let examples = parse_page(&page);
+    if examples.is_empty() {
+        bail!("No examples found in {}", html.display());
+    }
+    let folder = workspace.day_folder(day).join("examples");
+    fs::create_dir_all(&folder)?;
+    for (n, example) in examples.iter().enumerate() {
+        let input = folder.join(format!("example-{}.txt", n + 1));
+        fs::write(&input, &example.input)?;
+        example
+            .answers
+            .save(&folder.join(format!("example-{}.expected", n + 1)))?;
+        println!("Wrote {}", input.display());
+    }
+    Ok(())
+}
+
+/// Reads the string literal at the start of `code`, if there is one
+fn string_literal(code: &str) -> Option<String> {
+    if let Some(raw) = code.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let raw = raw[hashes..].strip_prefix('"')?;
+        let end = format!("\"{}", "#".repeat(hashes));
+        return raw.split_once(&end).map(|(text, _)| text.to_string());
+    }
+    let mut text = String::new();
+    let mut chars = code.strip_prefix('"')?.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Some(text),
+            '\\' => match chars.next()? {
+                'n' => text.push('\n'),
+                't' => text.push('\t'),
+                'r' => text.push('\r'),
+                '0' => text.push('\0'),
+                // A line continuation skips the leading whitespace of the next line
+                '\n' => {
+                    let rest = chars.as_str().trim_start();
+                    chars = rest.chars();
+                }
+                escaped => text.push(escaped),
+            },
+            c => text.push(c),
+        }
+    }
+    None
+}
+
+/// The `INPUT` constants of a day's source, such as `INPUT` or `INPUT2`, in
+/// order
+fn test_inputs(source: &str) -> Vec<String> {
+    source
+        .split("const INPUT")
+        .skip(1)
+        .filter_map(|constant| {
+            let (_, value) = constant.split_once(": &str =")?;
+            string_literal(value.trim_start())
+        })
+        .collect()
+}
+
+/// Example `n` of a day, starting at 1, with the file of its answers when
+/// they are known
+///
+/// Examples come from the `examples` folder written by [`extract_examples`]
+/// and otherwise from the `INPUT` constants of the day's tests, which are
+/// copied to the target folder to be run.
+pub fn example_input(
+    workspace: &Workspace,
+    day: u64,
+    n: usize,
+) -> anyhow::Result<(PathBuf, Option<PathBuf>)> {
+    let folder = workspace.day_folder(day);
+    let input = folder.join("examples").join(format!("example-{n}.txt"));
+    if input.exists() {
+        let expected = input.with_extension("expected");
+        return Ok((input, expected.exists().then_some(expected)));
+    }
+    let source = folder.join("src").join("lib.rs");
+    let source = fs::read_to_string(&source)
+        .with_context(|| format!("Could not read {}", source.display()))?;
+    let inputs = test_inputs(&source);
+    let Some(example) = n.checked_sub(1).and_then(|i| inputs.get(i)) else {
+        bail!(
+            "Day {day} has no example {n}, extract them with `cargo xtask examples {day} --html <page>`"
+        );
+    };
+    let target = workspace
+        .metadata()
+        .target_directory
+        .as_std_path()
+        .join("xtask");
+    fs::create_dir_all(&target)?;
+    let input = target.join(format!("{}-example-{n}.txt", workspace.package(day)));
+    fs::write(&input, example)?;
+    Ok((input, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::SolutionPart;
+
+    #[test]
+    fn test_parse_page() {
+        let page = r#"<main>
+<article class="day-desc"><h2>--- Day 1 ---</h2><p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre><pre><code>a &lt; b</code></pre>
+<p>Here, the answer is <code><em>11</em></code>.</p></article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><p>The score is <code><em>31</em></code>.</p></article>
+</main>"#;
+        assert_eq!(
+            parse_page(page),
+            [Example {
+                input: "3   4\n4   3\n".to_string(),
+                answers: Answers {
+                    part1: Some("11".to_string()),
+                    part2: Some("31".to_string()),
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_string_answer() {
+        let page = r#"<article class="day-desc"><pre><code>Register A: 729
+</code></pre><p>The output is <code><em>4,6,3,5,6,3,5,2,1,0</em></code>.</p></article>"#;
+        let [example] = parse_page(page).try_into().unwrap();
+        let mut report = utils::Report::quiet("day-17").expecting(example.answers);
+        report.answer(SolutionPart::PartOne, &"4,6,3,5,6,3,5,2,1,0".to_string());
+        assert_eq!(report.verified(SolutionPart::PartOne), Some(true));
+    }
+
+    #[test]
+    fn test_inputs_of_source() {
+        let source = r##"
+    const INPUT: &str = "1 2
+3 4";
+    const INPUT2: &str =
+        "a\"b\\c\
+         d";
+    const INPUT3: &str = r#"x"y"#;
+"##;
+        assert_eq!(test_inputs(source), ["1 2\n3 4", "a\"b\\cd", "x\"y"]);
+    }
+}
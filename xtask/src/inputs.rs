@@ -0,0 +1,238 @@
+use crate::{session::read_secret, workspace::Workspace};
+use anyhow::{anyhow, bail, Context};
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Component, Path},
+};
+
+/// Archive of the inputs, relative to the workspace root
+pub const ARCHIVE_FILE: &str = "inputs.enc";
+
+/// Start of an archive, followed by the salt, the nonce and the encrypted
+/// inputs
+const MAGIC: &[u8] = b"AOCINPUTS1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Inputs by path relative to the workspace root, with `/` separators
+type Inputs = BTreeMap<String, String>;
+
+fn cipher(passphrase: &str, salt: &[u8]) -> anyhow::Result<ChaCha20Poly1305> {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|error| anyhow!("Could not derive the key: {error}"))?;
+    Ok(ChaCha20Poly1305::new(&key))
+}
+
+/// Encrypts the inputs with a key derived from the passphrase
+///
+/// The authentication tag of the cipher covers every byte, so any change to
+/// the archive is caught by [`open`].
+fn seal(inputs: &Inputs, passphrase: &str) -> anyhow::Result<Vec<u8>> {
+    let mut salt = [0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher(passphrase, &salt)?
+        .encrypt(&nonce, serde_json::to_vec(inputs)?.as_slice())
+        .map_err(|_| anyhow!("Could not encrypt the inputs"))?;
+    Ok([MAGIC, &salt, &nonce, &ciphertext].concat())
+}
+
+/// Decrypts an archive made by [`seal`]
+fn open(archive: &[u8], passphrase: &str) -> anyhow::Result<Inputs> {
+    let Some(rest) = archive.strip_prefix(MAGIC) else {
+        bail!("Not an inputs archive");
+    };
+    if rest.len() < SALT_LEN + NONCE_LEN {
+        bail!("The archive is truncated");
+    }
+    let (salt, rest) = rest.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let plaintext = cipher(passphrase, salt)?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("Wrong passphrase, or the archive was changed"))?;
+    Ok(serde_json::from_slice(&plaintext)?)
+}
+
+/// Reads the passphrase from `AOC_INPUTS_PASSPHRASE`, or without echo from
+/// the terminal, twice with `confirm` so a typo can't lock the inputs away
+fn passphrase(confirm: bool) -> anyhow::Result<String> {
+    let passphrase = match env::var("AOC_INPUTS_PASSPHRASE") {
+        Ok(passphrase) => passphrase,
+        Err(_) => {
+            let passphrase = read_secret("Passphrase of the inputs archive:")?;
+            if confirm && read_secret("Repeat the passphrase:")? != passphrase {
+                bail!("The passphrases don't match");
+            }
+            passphrase
+        }
+    };
+    if passphrase.is_empty() {
+        bail!("The passphrase is empty");
+    }
+    Ok(passphrase)
+}
+
+/// Determines if a path of the archive is a plain `input.txt` below the
+/// workspace root
+fn is_input_path(path: &str) -> bool {
+    let path = Path::new(path);
+    path.file_name().is_some_and(|name| name == "input.txt")
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// Adds the local inputs to those of the archive, replacing the archived
+/// version of an input that changed
+fn merge(mut archived: Inputs, local: Inputs) -> Inputs {
+    for (name, content) in local {
+        match archived.get(&name) {
+            Some(existing) if *existing == content => {}
+            Some(_) => println!("Updating {name}"),
+            None => println!("Packing {name}"),
+        }
+        archived.insert(name, content);
+    }
+    archived
+}
+
+/// Bundles the input of every day package, of every year, into the
+/// encrypted archive
+///
+/// An existing archive is opened with the passphrase and kept up to date, so
+/// that inputs missing from this machine aren't lost.
+pub fn pack(workspace: &Workspace) -> anyhow::Result<()> {
+    let root = workspace.root();
+    let mut local = Inputs::new();
+    for package in workspace.metadata().workspace_packages() {
+        if !package.name.starts_with("day-") {
+            continue;
+        }
+        let Some(folder) = package.manifest_path.parent() else {
+            continue;
+        };
+        let input = folder.as_std_path().join("input.txt");
+        if !input.exists() {
+            continue;
+        }
+        let relative = input.strip_prefix(root)?;
+        let name = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        local.insert(name, fs::read_to_string(&input)?);
+    }
+    let archive = root.join(ARCHIVE_FILE);
+    let (archived, passphrase) = if archive.exists() {
+        let bytes =
+            fs::read(&archive).with_context(|| format!("Could not read {}", archive.display()))?;
+        let passphrase = passphrase(false)?;
+        let archived = open(&bytes, &passphrase)
+            .with_context(|| format!("Could not update {}", archive.display()))?;
+        (archived, passphrase)
+    } else {
+        (Inputs::new(), passphrase(true)?)
+    };
+    let inputs = merge(archived, local);
+    if inputs.is_empty() {
+        bail!("No input to pack");
+    }
+    fs::write(&archive, seal(&inputs, &passphrase)?)
+        .with_context(|| format!("Could not write {}", archive.display()))?;
+    println!("Packed {} inputs into {}", inputs.len(), archive.display());
+    Ok(())
+}
+
+/// Restores the inputs of the archive, leaving inputs that differ from the
+/// archive alone unless `force` is set
+///
+/// Inputs of days that aren't packages of the workspace are skipped, as a
+/// folder without a package would break the `day-*` members.
+pub fn unpack(workspace: &Workspace, force: bool) -> anyhow::Result<()> {
+    let root = workspace.root();
+    let archive = root.join(ARCHIVE_FILE);
+    let bytes =
+        fs::read(&archive).with_context(|| format!("Could not read {}", archive.display()))?;
+    let inputs = open(&bytes, &passphrase(false)?)
+        .with_context(|| format!("Could not unpack {}", archive.display()))?;
+    let mut differing = 0;
+    for (name, content) in &inputs {
+        if !is_input_path(name) {
+            bail!("Unexpected file {name} in the archive");
+        }
+        let path = root.join(name);
+        let folder = path.parent().unwrap_or(root);
+        if !folder.join("Cargo.toml").exists() {
+            println!("Skipping {name}, the day has no package");
+            continue;
+        }
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == *content => println!("{name} is up to date"),
+            Ok(_) if !force => {
+                println!("{name} differs from the archive, use --force to replace it");
+                differing += 1;
+            }
+            _ => {
+                println!("Restoring {name}");
+                fs::write(&path, content)?;
+            }
+        }
+    }
+    if differing > 0 {
+        bail!("{differing} inputs differ from the archive");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_open() {
+        let inputs = Inputs::from([
+            ("day-01/input.txt".to_string(), "3   4\n".to_string()),
+            ("2023/day-01/input.txt".to_string(), "1abc2\n".to_string()),
+        ]);
+        let mut archive = seal(&inputs, "hunter2").unwrap();
+        assert_eq!(open(&archive, "hunter2").unwrap(), inputs);
+        assert!(open(&archive, "hunter3").is_err());
+        *archive.last_mut().unwrap() ^= 1;
+        assert!(open(&archive, "hunter2").is_err());
+    }
+
+    #[test]
+    fn test_merge() {
+        let archived = Inputs::from([
+            ("day-01/input.txt".to_string(), "3   4\n".to_string()),
+            ("day-02/input.txt".to_string(), "7 6 4\n".to_string()),
+        ]);
+        let local = Inputs::from([
+            ("day-02/input.txt".to_string(), "1 2 7\n".to_string()),
+            ("day-03/input.txt".to_string(), "mul(2,4)\n".to_string()),
+        ]);
+        let merged = merge(archived, local);
+        assert_eq!(
+            merged.values().map(String::as_str).collect::<Vec<_>>(),
+            ["3   4\n", "1 2 7\n", "mul(2,4)\n"]
+        );
+    }
+
+    #[test]
+    fn test_is_input_path() {
+        assert!(is_input_path("day-01/input.txt"));
+        assert!(is_input_path("2023/day-01/input.txt"));
+        assert!(!is_input_path("../day-01/input.txt"));
+        assert!(!is_input_path("/etc/input.txt"));
+        assert!(!is_input_path("day-01/src/lib.rs"));
+    }
+}
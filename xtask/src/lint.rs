@@ -0,0 +1,217 @@
+use crate::workspace::Workspace;
+use anyhow::{bail, Context};
+use cargo_metadata::TargetKind;
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A convention of the days that a file breaks
+#[derive(Debug)]
+struct Violation {
+    file: PathBuf,
+    line: usize,
+    message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.file.display(), self.line, self.message)
+    }
+}
+
+/// Line of the manifest declaring a dependency, 1 when it can't be found
+fn dependency_line(manifest: &str, name: &str) -> usize {
+    manifest
+        .lines()
+        .position(|line| {
+            line.trim_start()
+                .strip_prefix(name)
+                .is_some_and(|rest| rest.starts_with(['.', ' ', '=']))
+        })
+        .map_or(1, |i| i + 1)
+}
+
+/// Checks that every dependency of a day comes from `[workspace.dependencies]`
+fn check_manifest(text: &str) -> anyhow::Result<Vec<(usize, String)>> {
+    let manifest: toml::Table = toml::from_str(text)?;
+    let mut violations = Vec::new();
+    for section in ["dependencies", "dev-dependencies"] {
+        let Some(dependencies) = manifest.get(section).and_then(toml::Value::as_table) else {
+            continue;
+        };
+        for (name, dependency) in dependencies {
+            let inherited = dependency
+                .get("workspace")
+                .and_then(toml::Value::as_bool)
+                .unwrap_or(false);
+            if !inherited {
+                violations.push((
+                    dependency_line(text, name),
+                    format!("`{name}` is not taken from [workspace.dependencies]"),
+                ));
+            }
+        }
+    }
+    Ok(violations)
+}
+
+/// Checks the source of a day against the template: it imports `utils`, has
+/// tests for the parser and both parts and doesn't `unwrap()` while parsing
+fn check_source(source: &str) -> Vec<(usize, String)> {
+    let mut violations = Vec::new();
+    let lines: Vec<&str> = source.lines().collect();
+    let find = |needle: &str| lines.iter().position(|line| line.contains(needle));
+
+    if find("use utils::*;").is_none() {
+        violations.push((1, "missing `use utils::*;`".to_string()));
+    }
+
+    match find("mod tests") {
+        None => violations.push((1, "missing `mod tests`".to_string())),
+        Some(tests) => {
+            for test in ["test_parser", "test_part1", "test_part2"] {
+                if find(&format!("fn {test}(")).is_none() {
+                    violations.push((tests + 1, format!("missing test `{test}`")));
+                }
+            }
+        }
+    }
+
+    // The free `parse` function, which ends at the first closing brace that
+    // isn't indented
+    if let Some(start) = lines
+        .iter()
+        .position(|line| line.starts_with("fn parse(") || line.starts_with("pub fn parse("))
+    {
+        for (i, line) in lines.iter().enumerate().skip(start + 1) {
+            if line.starts_with('}') {
+                break;
+            }
+            if line.contains(".unwrap()") {
+                violations.push((
+                    i + 1,
+                    "`unwrap()` in `parse`, return a parse error instead".to_string(),
+                ));
+            }
+        }
+    }
+    violations
+}
+
+/// Checks every `day-*` package of the workspace against the conventions of
+/// the templates, printing each violation with its location
+pub fn lint(workspace: &Workspace) -> anyhow::Result<()> {
+    let root = workspace.root();
+    let mut days = 0;
+    let mut violations = Vec::new();
+    for package in workspace.metadata().workspace_packages() {
+        if !package.name.starts_with("day-") {
+            continue;
+        }
+        days += 1;
+        let manifest_path = package.manifest_path.as_std_path();
+        let manifest = fs::read_to_string(manifest_path)?;
+        let relative = |path: &Path| path.strip_prefix(root).unwrap_or(path).to_path_buf();
+        for (line, message) in check_manifest(&manifest)
+            .with_context(|| format!("Invalid {}", manifest_path.display()))?
+        {
+            violations.push(Violation {
+                file: relative(manifest_path),
+                line,
+                message,
+            });
+        }
+
+        let Some(target) = package
+            .targets
+            .iter()
+            .find(|target| target.kind.contains(&TargetKind::Lib))
+            .or_else(|| package.targets.first())
+        else {
+            continue;
+        };
+        let source_path = target.src_path.as_std_path();
+        let source = fs::read_to_string(source_path)?;
+        for (line, message) in check_source(&source) {
+            violations.push(Violation {
+                file: relative(source_path),
+                line,
+                message,
+            });
+        }
+    }
+
+    for violation in &violations {
+        println!("{violation}");
+    }
+    if violations.is_empty() {
+        println!("All {days} days follow the conventions");
+        Ok(())
+    } else {
+        bail!("{} violations in {days} days", violations.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_manifest() {
+        let manifest = r#"[package]
+name = "day-04"
+
+[dependencies]
+itertools.workspace = true
+ndarray = "0.16.1"
+utils = { path = "../utils", version = "*" }
+"#;
+        let violations = check_manifest(manifest).unwrap();
+        assert_eq!(
+            violations,
+            [
+                (
+                    6,
+                    "`ndarray` is not taken from [workspace.dependencies]".to_string()
+                ),
+                (
+                    7,
+                    "`utils` is not taken from [workspace.dependencies]".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_source() {
+        let source = "use utils::*;
+
+fn parse(input: &str) -> ParseResult<'_, InputData> {
+    let value = input.parse().unwrap();
+    Ok((\"\", value))
+}
+
+fn part1(input: &InputData) -> usize {
+    input.0.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    fn test_parser() {}
+    fn test_part1() {}
+}
+";
+        assert_eq!(
+            check_source(source),
+            [
+                (13, "missing test `test_part2`".to_string()),
+                (
+                    4,
+                    "`unwrap()` in `parse`, return a parse error instead".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -1,17 +1,76 @@
+mod aoc;
+mod bench;
 mod create;
+mod examples;
+mod inputs;
+mod lint;
+mod readme;
+mod session;
+mod status;
+mod submit;
+mod test_all;
+mod watch;
+mod workspace;
 
-use cargo_metadata::{Metadata, MetadataCommand};
-use clap::{builder::PossibleValue, Parser, ValueEnum};
+use clap::{builder::PossibleValue, CommandFactory, Parser, Subcommand, ValueEnum};
 use log::trace;
-use std::fmt::Display;
+use std::{fmt::Display, path::PathBuf};
 use xshell::{cmd, Shell};
 
-use crate::create::{generate_day, generate_input};
+use crate::{
+    bench::bench,
+    create::{generate_day, generate_input},
+    examples::{example_input, extract_examples},
+    lint::lint,
+    readme::readme,
+    status::status,
+    submit::submit,
+    test_all::test_all,
+    watch::{watch, Action},
+    workspace::Workspace,
+};
 
 /// Tasks to use and maintain this project
 #[derive(Parser, Debug)]
 #[command(author, about, long_about = None)]
-enum Cli {
+struct Cli {
+    /// Year of the event, instead of the one in `[workspace.metadata.aoc]`
+    #[arg(long, global = true, env = "AOC_YEAR", hide_env_values = true, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+
+    /// Session key profile, instead of the default `~/.adventofcode`
+    #[arg(long, global = true, env = "AOC_PROFILE", hide_env_values = true, value_parser = session::parse_profile)]
+    profile: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Times the solutions on their input and saves the results
+    Bench {
+        /// The day to time, all days with an input when left out
+        #[arg(value_parser = clap::value_parser!(u64).range(1..=25))]
+        day: Option<u64>,
+
+        /// Number of times to run each day
+        #[arg(short, long, default_value_t = 20)]
+        runs: usize,
+
+        /// Save the results as the named baseline
+        #[arg(long, value_name = "NAME")]
+        save_baseline: Option<String>,
+
+        /// Fail if a day is slower than the named baseline
+        #[arg(long, value_name = "NAME")]
+        compare: Option<String>,
+
+        /// Percentage a phase may be slower than the baseline
+        #[arg(long, default_value_t = 10.0, requires = "compare")]
+        threshold: f64,
+    },
+
     /// Runs clippy on all projects
     Clippy,
 
@@ -20,6 +79,11 @@ enum Cli {
         /// The day to run
         #[arg(value_parser = clap::value_parser!(u64).range(1..=25))]
         day: u64,
+
+        /// Folder of `templates` to start from, such as `minimal`, `parser`
+        /// or `grid`
+        #[arg(short, long, default_value = "minimal")]
+        template: String,
     },
 
     /// Run the solution for the day
@@ -31,6 +95,67 @@ enum Cli {
         /// part of the task to do
         #[arg(short, long, value_enum, default_value_t)]
         part: SolutionPart,
+
+        /// Run an example instead of `input.txt`, the first when no number is
+        /// given
+        #[arg(short, long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+        example: Option<usize>,
+
+        /// Run this file instead of `input.txt`, without checking the answers
+        /// unless `--expect <FILE>` is given to the day after `--`
+        #[arg(short, long, conflicts_with = "example")]
+        input: Option<PathBuf>,
+
+        /// Arguments for the day, after `--`
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+
+    /// Extracts the examples and their answers from a saved puzzle page
+    Examples {
+        /// The day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u64).range(1..=25))]
+        day: u64,
+
+        /// The puzzle page, saved from the browser
+        #[arg(long)]
+        html: PathBuf,
+    },
+
+    /// Stores the inputs in an encrypted archive that can be committed
+    Inputs {
+        #[command(subcommand)]
+        action: InputsAction,
+    },
+
+    /// Checks the days against the conventions of the templates
+    Lint,
+
+    /// Rewrites the help and the table of days in README.md
+    Readme,
+
+    /// Manages the session key used with adventofcode.com
+    Session {
+        #[command(subcommand)]
+        action: SessionAction,
+    },
+
+    /// Lists the days with their input, tests, answers and time
+    Status {
+        /// Leave out the tests, which have to be built and run
+        #[arg(long)]
+        no_test: bool,
+    },
+
+    /// Solves a part of a day and submits the answer
+    Submit {
+        /// The day to submit
+        #[arg(value_parser = clap::value_parser!(u64).range(1..=25))]
+        day: u64,
+
+        /// The part to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
     },
 
     /// Print out a lovely christmas tree
@@ -43,8 +168,53 @@ enum Cli {
         day: u64,
     },
 
-    /// Test all days
+    /// Test all days in parallel and summarise the results
     TestAll,
+
+    /// Re-runs the tests or the solution of a day whenever its sources,
+    /// `utils` or its input change
+    Watch {
+        /// The day to watch
+        #[arg(value_parser = clap::value_parser!(u64).range(1..=25))]
+        day: u64,
+
+        /// Run the solution on the input
+        #[arg(long, conflicts_with = "test")]
+        run: bool,
+
+        /// Run the tests, the default
+        #[arg(long)]
+        test: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum InputsAction {
+    /// Encrypts the input of every day into `inputs.enc`, keeping the
+    /// archived inputs missing here
+    Pack,
+
+    /// Restores the inputs from `inputs.enc`
+    Unpack {
+        /// Replace inputs that differ from the archive
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum SessionAction {
+    /// Saves the session key, only readable by you
+    Set {
+        /// The `session` cookie of the website, read from stdin when left out
+        token: Option<String>,
+    },
+
+    /// Checks that the website accepts the session key
+    Check,
+
+    /// Shows where the session key comes from
+    Show,
 }
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -99,63 +269,122 @@ fn main() -> anyhow::Result<()> {
     env_logger::init();
     let cli = Cli::parse();
     trace!("CLI arguments: {cli:?}");
-    let metadata = MetadataCommand::new().no_deps().exec()?;
+    let workspace = Workspace::load(cli.year, cli.profile)?;
 
     let sh = Shell::new()?;
-    match cli {
-        Cli::Clippy => {
+    match cli.command {
+        Command::Bench {
+            day,
+            runs,
+            save_baseline,
+            compare,
+            threshold,
+        } => {
+            let run = bench(&sh, &workspace, day, runs)?;
+            if let Some(name) = compare {
+                bench::compare(&workspace, &name, &run, threshold)?;
+            }
+            if let Some(name) = save_baseline {
+                bench::save_baseline(&workspace, &name, &run)?;
+            }
+        }
+        Command::Clippy => {
             cmd!(
                 sh,
                 "cargo clippy -q -- -W clippy::all -W clippy::pedantic -W clippy::nursery"
             )
             .run()?;
         }
-        Cli::Create { day } => {
-            generate_day(day, &metadata)?;
-        }
-        Cli::Day { day, part } => {
-            let package = format!("day-{day:0>2}");
-            let path = metadata
-                .workspace_root
-                .as_std_path()
-                .join(&package)
-                .join("input.txt");
-            if !path.exists() {
-                generate_input(day, &path)?;
-            }
+        Command::Create { day, template } => {
+            generate_day(day, &template, &workspace)?;
+        }
+        Command::Day {
+            day,
+            part,
+            example,
+            input,
+            mut args,
+        } => {
+            let package = workspace.package(day);
+            let path = if let Some(n) = example {
+                let (path, expected) = example_input(&workspace, day, n)?;
+                if let Some(expected) = expected {
+                    args.extend(["--expect".to_string(), expected.display().to_string()]);
+                }
+                path
+            } else if let Some(path) = input {
+                // The answers of the day are only those of its input.txt
+                if !args
+                    .iter()
+                    .any(|arg| arg.starts_with("--expect") || arg == "--record")
+                {
+                    args.push("--no-expect".to_string());
+                }
+                path
+            } else {
+                let path = workspace.input(day);
+                if !path.exists() {
+                    generate_input(&workspace, day, &path)?;
+                }
+                path
+            };
             let part = format!("{part}");
+            // --mem needs the counting allocator of utils
+            let features = if args.iter().any(|arg| arg == "--mem") {
+                &["--features", "utils/count-alloc"][..]
+            } else {
+                &[]
+            };
             sh.set_var("RUSTFLAGS", "-Awarnings");
             cmd!(
                 sh,
-                "cargo run -q --release --package {package} -- {path} -p {part}"
+                "cargo run -q --release --package {package} {features...} -- {path} -p {part} {args...}"
             )
             .run()?;
         }
-        Cli::Tree => {
+        Command::Examples { day, html } => {
+            extract_examples(day, &html, &workspace)?;
+        }
+        Command::Inputs { action } => match action {
+            InputsAction::Pack => inputs::pack(&workspace)?,
+            InputsAction::Unpack { force } => inputs::unpack(&workspace, force)?,
+        },
+        Command::Lint => lint(&workspace)?,
+        Command::Readme => {
+            readme(&workspace, &Cli::command().render_help().to_string())?;
+        }
+        Command::Session { action } => match action {
+            SessionAction::Set { token } => session::set(workspace.profile(), token)?,
+            SessionAction::Check => session::check(&workspace)?,
+            SessionAction::Show => session::show(workspace.profile())?,
+        },
+        Command::Status { no_test } => {
+            status(&workspace, !no_test)?;
+        }
+        Command::Submit { day, part } => {
+            submit(&sh, &workspace, day, part)?;
+        }
+        Command::Tree => {
             cmd!(sh, "cargo run -q --release --package tree").run()?;
         }
-        Cli::Test { day } => {
-            let day = format!("day-{day:0>2}");
+        Command::Test { day } => {
+            let day = workspace.package(day);
             cmd!(sh, "cargo test -q --package {day}").run()?;
         }
-        Cli::TestAll => {
-            test_all(&sh, &metadata)?;
+        Command::TestAll => {
+            test_all(&workspace)?;
+        }
+        Command::Watch { day, run, test: _ } => {
+            let action = if run { Action::Run } else { Action::Test };
+            if action == Action::Run {
+                let path = workspace.input(day);
+                if !path.exists() {
+                    generate_input(&workspace, day, &path)?;
+                }
+            }
+            watch(&sh, &workspace, day, action)?;
         }
     }
 
     Ok(())
 }
-
-/// Tests all of the Advent of Code projects in the workspace
-fn test_all(sh: &Shell, metadata: &Metadata) -> anyhow::Result<()> {
-    metadata
-        .workspace_packages()
-        .iter()
-        .filter(|p| p.name.starts_with("day"))
-        .try_for_each(|p| {
-            let name = p.name.clone();
-            cmd!(sh, "cargo test -q -p {name}")
-                .run()
-                .map_err(anyhow::Error::from)
-        })
-}
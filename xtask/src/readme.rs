@@ -0,0 +1,95 @@
+use crate::workspace::Workspace;
+use anyhow::bail;
+use std::fs;
+
+/// Replaces the text between `<!-- xtask:NAME -->` and `<!-- /xtask:NAME -->`
+fn replace_section(readme: &str, name: &str, content: &str) -> anyhow::Result<String> {
+    let start = format!("<!-- xtask:{name} -->");
+    let end = format!("<!-- /xtask:{name} -->");
+    let Some((before, rest)) = readme.split_once(&start) else {
+        bail!("README.md has no {start} marker");
+    };
+    let Some((_, after)) = rest.split_once(&end) else {
+        bail!("README.md has no {end} marker");
+    };
+    Ok(format!(
+        "{before}{start}\n{}\n{end}{after}",
+        content.trim_end()
+    ))
+}
+
+/// Markdown table of the days, linked to their puzzles, with the number of
+/// stars of each
+fn days_rows(year: u16, stars: &[(u64, usize)]) -> String {
+    let mut table = "| Day | Stars |\n| --: | ----- |\n".to_string();
+    for (day, count) in stars {
+        table.push_str(&format!(
+            "| [{day}](https://adventofcode.com/{year}/day/{day}) | {} |\n",
+            "⭐".repeat(*count)
+        ));
+    }
+    table
+}
+
+/// Table of the days with the stars recorded in their `answers.toml`
+///
+/// Only committed files are read, so that the table doesn't change with the
+/// inputs or the speed of the machine it is generated on.
+fn days_table(workspace: &Workspace) -> anyhow::Result<String> {
+    let mut stars = Vec::new();
+    for (day, _) in workspace.days() {
+        let answers = workspace.day_folder(day).join("answers.toml");
+        let recorded = if answers.exists() {
+            utils::Answers::load(&answers)?
+        } else {
+            utils::Answers::default()
+        };
+        let count = [&recorded.part1, &recorded.part2]
+            .iter()
+            .filter(|answer| answer.is_some())
+            .count();
+        stars.push((day, count));
+    }
+    Ok(days_rows(workspace.year(), &stars))
+}
+
+/// Rewrites the marked sections of `README.md`: `help` with the help of
+/// xtask and `days` with a table of the days
+pub fn readme(workspace: &Workspace, help: &str) -> anyhow::Result<()> {
+    let path = workspace.root().join("README.md");
+    let readme = fs::read_to_string(&path)?;
+    let readme = replace_section(
+        &readme,
+        "help",
+        &format!("```shell-session\n{}\n```", help.trim_end()),
+    )?;
+    let readme = replace_section(&readme, "days", &days_table(workspace)?)?;
+    fs::write(&path, readme)?;
+    println!("Updated {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# Title\n<!-- xtask:help -->\nold\n<!-- /xtask:help -->\nrest\n";
+        assert_eq!(
+            replace_section(readme, "help", "new\n").unwrap(),
+            "# Title\n<!-- xtask:help -->\nnew\n<!-- /xtask:help -->\nrest\n"
+        );
+        assert!(replace_section(readme, "days", "").is_err());
+    }
+
+    #[test]
+    fn test_days_rows() {
+        assert_eq!(
+            days_rows(2024, &[(1, 2), (2, 0)]),
+            "| Day | Stars |\n| --: | ----- |\n\
+             | [1](https://adventofcode.com/2024/day/1) | ⭐⭐ |\n\
+             | [2](https://adventofcode.com/2024/day/2) |  |\n"
+        );
+    }
+}
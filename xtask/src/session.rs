@@ -0,0 +1,205 @@
+use crate::{aoc::AocClient, workspace::Workspace};
+use anyhow::{bail, Context};
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable that overrides the session key of the default profile
+const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Where a session key came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Env,
+    File(PathBuf),
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Env => write!(f, "the {SESSION_VAR} environment variable"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Checks that a profile name is only made of ASCII letters, digits, `_` and
+/// `-`, so its file stays in the home directory
+pub fn parse_profile(name: &str) -> Result<String, String> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    {
+        Ok(name.to_string())
+    } else {
+        Err(format!(
+            "{name:?} is not a profile name, use letters, digits, `_` and `-`"
+        ))
+    }
+}
+
+/// File holding the session key of a profile
+///
+/// The default profile is `~/.adventofcode` and a named one is
+/// `~/.adventofcode-<name>`.
+pub fn profile_file(profile: Option<&str>) -> anyhow::Result<PathBuf> {
+    let home = dirs::home_dir().context("No home directory")?;
+    Ok(match profile {
+        Some(name) => home.join(format!(".adventofcode-{name}")),
+        None => home.join(".adventofcode"),
+    })
+}
+
+/// Removes the whitespace and `session=` prefix of a pasted key
+fn clean(token: &str) -> &str {
+    let token = token.trim();
+    token.strip_prefix("session=").unwrap_or(token)
+}
+
+/// Reads the session key from the file of the profile when one is given, or
+/// else from `AOC_SESSION` or the file of the default profile
+pub fn read_session(profile: Option<&str>) -> anyhow::Result<(String, Source)> {
+    if profile.is_none() {
+        if let Ok(token) = env::var(SESSION_VAR) {
+            if !clean(&token).is_empty() {
+                return Ok((clean(&token).to_string(), Source::Env));
+            }
+        }
+    }
+    let path = profile_file(profile)?;
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            let or_env = if profile.is_none() {
+                format!(" or {SESSION_VAR}")
+            } else {
+                String::new()
+            };
+            bail!(
+                "No session key in {}{or_env}, save one with `cargo xtask session set`",
+                path.display()
+            )
+        }
+        Err(error) => {
+            return Err(error).with_context(|| format!("Could not read {}", path.display()))
+        }
+    };
+    let token = clean(&content);
+    if token.is_empty() {
+        bail!("The session key in {} is empty", path.display());
+    }
+    Ok((token.to_string(), Source::File(path)))
+}
+
+/// Reads a secret from the terminal without echoing it, or a line of stdin
+/// when it isn't a terminal
+pub fn read_secret(prompt: &str) -> io::Result<String> {
+    if io::stdin().is_terminal() {
+        rpassword::prompt_password(format!("{prompt} "))
+    } else {
+        println!("{prompt}");
+        let mut secret = String::new();
+        io::stdin().read_line(&mut secret)?;
+        Ok(secret.trim_end_matches(['\r', '\n']).to_string())
+    }
+}
+
+/// Writes a file only its owner can read
+fn write_private(path: &Path, content: &str) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // The mode only applies to new files
+        if path.exists() {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+    options.open(path)?.write_all(content.as_bytes())
+}
+
+/// Saves the session key of a profile, reading it without echo when not given
+/// so it stays out of the shell history and off the screen
+pub fn set(profile: Option<&str>, token: Option<String>) -> anyhow::Result<()> {
+    let token = match token {
+        Some(token) => token,
+        None => read_secret("Paste the session cookie of adventofcode.com:")?,
+    };
+    let token = clean(&token);
+    if token.is_empty() {
+        bail!("The session key is empty");
+    }
+    let path = profile_file(profile)?;
+    write_private(&path, &format!("{token}\n"))
+        .with_context(|| format!("Could not write {}", path.display()))?;
+    println!("Saved the session key to {}", path.display());
+    Ok(())
+}
+
+/// Warns when the session key file can be read by other users
+fn check_permissions(source: &Source) -> anyhow::Result<()> {
+    #[cfg(unix)]
+    if let Source::File(path) = source {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(path)?.permissions().mode();
+        if mode & 0o077 != 0 {
+            println!(
+                "Warning: {} can be read by others, run `chmod 600 {}`",
+                path.display(),
+                path.display()
+            );
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = source;
+    Ok(())
+}
+
+/// Checks that the session key is accepted by the website
+pub fn check(workspace: &Workspace) -> anyhow::Result<()> {
+    let (session, source) = read_session(workspace.profile())?;
+    check_permissions(&source)?;
+    match AocClient::with_session(workspace, session).user()? {
+        Some(user) => {
+            println!("The session key from {source} is logged in as {user}");
+            Ok(())
+        }
+        None => bail!("The session key from {source} is not logged in, it may have expired"),
+    }
+}
+
+/// Shows where the session key comes from, without revealing it
+pub fn show(profile: Option<&str>) -> anyhow::Result<()> {
+    let (token, source) = read_session(profile)?;
+    let shown: String = token.chars().take(6).collect();
+    println!("{shown}… ({} characters) from {source}", token.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean() {
+        assert_eq!(clean("  abc123\n"), "abc123");
+        assert_eq!(clean("session=abc123"), "abc123");
+        assert_eq!(clean("\n"), "");
+    }
+
+    #[test]
+    fn test_parse_profile() {
+        assert_eq!(parse_profile("work_2-b").unwrap(), "work_2-b");
+        assert!(parse_profile("").is_err());
+        assert!(parse_profile("../x").is_err());
+        assert!(parse_profile("a/b").is_err());
+        assert!(parse_profile("a.b").is_err());
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    bench::{load_history, HISTORY_FILE},
+    test_all::run_tests,
+    workspace::Workspace,
+};
+use std::{fs, path::Path, time::Duration};
+use utils::{table::print_table, Answers};
+
+const HEADER: [&str; 8] = [
+    "Day", "Created", "Input", "Tests", "Part 2", "Answer 1", "Answer 2", "Time",
+];
+
+fn mark(yes: bool) -> String {
+    if yes { "✓" } else { "✗" }.to_string()
+}
+
+/// Determines if part 2 of a day is still the stub of the template
+///
+/// That is when it answers `()` or its test is commented out.
+fn part2_is_stub(source: &str) -> bool {
+    let returns_unit = source.split("fn part2(").skip(1).any(|part2| {
+        part2
+            .split_once('{')
+            .is_some_and(|(signature, _)| signature.contains("AocResult<()>"))
+    });
+    returns_unit || source.contains("// assert_part!(parse, part2")
+}
+
+/// Lists days 1 to 25 with how far along they are
+///
+/// The tests of the days are run unless `test` is false, and the time is the
+/// median total of the latest `xtask bench` of the day.
+pub fn status(workspace: &Workspace, test: bool) -> anyhow::Result<()> {
+    let days = workspace.days();
+    let tests = if test && !days.is_empty() {
+        run_tests(workspace, &days)?
+    } else {
+        Vec::new()
+    };
+    let history = load_history(&workspace.root().join(HISTORY_FILE))?;
+
+    let rows: Vec<[String; 8]> = (1..=25)
+        .map(|day| {
+            let input = mark(workspace.input(day).exists());
+            let Some((_, package)) = days.iter().find(|(number, _)| *number == day) else {
+                let mut row: [String; 8] = Default::default();
+                row[..3].clone_from_slice(&[day.to_string(), mark(false), input]);
+                return row;
+            };
+            let folder = workspace.day_folder(day);
+            let source = fs::read_to_string(folder.join("src").join("lib.rs"))
+                .or_else(|_| fs::read_to_string(folder.join("src").join("main.rs")))
+                .unwrap_or_default();
+            let answers = answers(&folder.join("answers.toml"));
+            let time = history
+                .iter()
+                .rev()
+                .find_map(|run| run.days.get(package)?.get("total"))
+                .map_or(String::new(), |stats| {
+                    format!("{:.2?}", Duration::from_nanos(stats.median))
+                });
+            [
+                day.to_string(),
+                mark(true),
+                input,
+                tests
+                    .iter()
+                    .find(|(name, _)| name == package)
+                    .map_or(String::new(), |(_, result)| mark(result.ok())),
+                if part2_is_stub(&source) {
+                    "stub"
+                } else {
+                    "done"
+                }
+                .to_string(),
+                answers.part1.unwrap_or_default(),
+                answers.part2.unwrap_or_default(),
+                time,
+            ]
+        })
+        .collect();
+    print_table(HEADER, &rows);
+    Ok(())
+}
+
+/// The recorded answers of a day, or none when it has no readable
+/// `answers.toml`
+fn answers(path: &Path) -> Answers {
+    if path.exists() {
+        Answers::load(path).unwrap_or_default()
+    } else {
+        Answers::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part2_is_stub() {
+        let stub = "#[allow(clippy::unnecessary_wraps)]
+fn part2(_input: &InputData) -> AocResult<()> {
+    Ok(())
+}";
+        assert!(part2_is_stub(stub));
+        let done = "fn part2(input: &InputData) -> AocResult<usize> {
+    Ok(0)
+}
+    fn test_part2() {
+        assert_part!(parse, part2, INPUT, 6);
+    }";
+        assert!(!part2_is_stub(done));
+        assert!(part2_is_stub(
+            &done.replace("assert_part!", "// assert_part!")
+        ));
+    }
+}
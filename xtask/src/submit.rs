@@ -0,0 +1,163 @@
+use crate::{
+    aoc::{AocClient, Verdict},
+    create::generate_input,
+    workspace::Workspace,
+};
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{fs, path::Path};
+use utils::Answers;
+use xshell::{cmd, Shell};
+
+/// An answer the website turned down
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Guess {
+    answer: String,
+    verdict: Verdict,
+}
+
+/// Wrong answers given so far for a day, stored as `guesses.toml`
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Guesses {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Guess>,
+}
+
+impl Guesses {
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        toml::from_str(&fs::read_to_string(path)?)
+            .with_context(|| format!("Invalid guesses file {}", path.display()))
+    }
+
+    fn save(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Could not write guesses to {}", path.display()))
+    }
+
+    fn part_mut(&mut self, part: u8) -> &mut Vec<Guess> {
+        if part == 1 {
+            &mut self.part1
+        } else {
+            &mut self.part2
+        }
+    }
+
+    /// Why `answer` is known to be wrong, if it is
+    ///
+    /// Besides the answers already given, numbers past an answer that was too
+    /// high or too low are wrong too.
+    fn rejects(guesses: &[Guess], answer: &str) -> Option<String> {
+        if let Some(guess) = guesses.iter().find(|guess| guess.answer == answer) {
+            return Some(format!("{answer} was already guessed: {}", guess.verdict));
+        }
+        let answer: i128 = answer.parse().ok()?;
+        guesses.iter().find_map(|guess| {
+            let guessed: i128 = guess.answer.parse().ok()?;
+            match guess.verdict {
+                Verdict::TooHigh if answer >= guessed => {
+                    Some(format!("{answer} is too high, {guessed} already was"))
+                }
+                Verdict::TooLow if answer <= guessed => {
+                    Some(format!("{answer} is too low, {guessed} already was"))
+                }
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Runs a part of a day on its input and reads the answer
+fn solve(sh: &Shell, package: &str, input: &Path, part: u8) -> anyhow::Result<String> {
+    let part = part.to_string();
+    sh.set_var("RUSTFLAGS", "-Awarnings");
+    let output = cmd!(
+        sh,
+        "cargo run -q --release --package {package} -- {input} -p {part} --format json"
+    )
+    .read()?;
+    let report: Value = serde_json::from_str(&output)?;
+    let answer = report["answer"][format!("part{part}")]
+        .as_str()
+        .with_context(|| format!("{package} gave no answer to part {part}"))?;
+    Ok(answer.to_string())
+}
+
+/// Determines if an answer is what a part that isn't solved yet gives, like
+/// the `()` of the template's stub
+fn is_stub_answer(answer: &str) -> bool {
+    matches!(answer.trim(), "" | "()")
+}
+
+/// Solves a part of a day and submits the answer
+///
+/// Empty and `()` answers, and answers that are known to be wrong, aren't
+/// sent. A right answer is recorded in the day's `answers.toml`.
+pub fn submit(sh: &Shell, workspace: &Workspace, day: u64, part: u8) -> anyhow::Result<()> {
+    let package = workspace.package(day);
+    let folder = workspace.day_folder(day);
+    let input = workspace.input(day);
+    if !input.exists() {
+        generate_input(workspace, day, &input)?;
+    }
+    let answers_file = folder.join("answers.toml");
+    let mut answers = if answers_file.exists() {
+        Answers::load(&answers_file)?
+    } else {
+        Answers::default()
+    };
+    let known = if part == 1 {
+        &mut answers.part1
+    } else {
+        &mut answers.part2
+    };
+    if let Some(known) = known {
+        println!("Part {part} was already answered with {known}");
+        return Ok(());
+    }
+
+    let answer = solve(sh, &package, &input, part)?;
+    if is_stub_answer(&answer) {
+        bail!("Not submitting {answer:?}, part {part} of day {day} isn't solved yet");
+    }
+    let guesses_file = folder.join("guesses.toml");
+    let mut guesses = Guesses::load(&guesses_file)?;
+    if let Some(reason) = Guesses::rejects(guesses.part_mut(part), &answer) {
+        bail!("Not submitting, {reason}");
+    }
+
+    println!("Submitting {answer} for day {day} part {part}");
+    let verdict = AocClient::for_workspace(workspace)?.submit(day, part, &answer)?;
+    println!("{verdict}");
+    if verdict == Verdict::Right {
+        *known = Some(answer);
+        answers.save(&answers_file)?;
+        println!("Recorded the answer in {}", answers_file.display());
+        Ok(())
+    } else {
+        if verdict.is_wrong() {
+            guesses.part_mut(part).push(Guess { answer, verdict });
+            guesses.save(&guesses_file)?;
+        }
+        bail!("Day {day} part {part} was not accepted")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_stub_answer() {
+        assert!(is_stub_answer("()"));
+        assert!(is_stub_answer(""));
+        assert!(is_stub_answer(" \n"));
+        assert!(!is_stub_answer("0"));
+        assert!(!is_stub_answer("ABC"));
+    }
+}
@@ -0,0 +1,286 @@
+use crate::workspace::Workspace;
+use anyhow::bail;
+use cargo_metadata::{diagnostic::DiagnosticLevel, Message, PackageId};
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufReader,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+};
+use utils::table::print_table;
+
+/// Outcome of the tests of a day
+#[derive(Debug, Default)]
+pub struct DayResult {
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+    /// Names of the failed tests
+    failures: Vec<String>,
+    /// Output of the test executables that failed
+    output: Vec<String>,
+    /// Whether the tests compiled
+    built: bool,
+}
+
+impl DayResult {
+    pub const fn ok(&self) -> bool {
+        self.built && self.failed == 0 && self.output.is_empty()
+    }
+
+    /// Adds the results printed by a libtest executable
+    fn add_output(&mut self, output: &str, success: bool) {
+        for line in output.lines() {
+            if let Some(name) = line
+                .strip_prefix("test ")
+                .and_then(|line| line.strip_suffix(" ... FAILED"))
+            {
+                self.failures.push(name.to_string());
+            }
+            if let Some(summary) = line.strip_prefix("test result: ") {
+                for count in summary.split(['.', ';']) {
+                    let mut words = count.split_whitespace();
+                    let (Some(n), Some(kind)) = (words.next(), words.next()) else {
+                        continue;
+                    };
+                    let Ok(n) = n.parse::<usize>() else {
+                        continue;
+                    };
+                    match kind {
+                        "passed" => self.passed += n,
+                        "failed" => self.failed += n,
+                        "ignored" => self.ignored += n,
+                        _ => (),
+                    }
+                }
+            }
+        }
+        if !success {
+            self.output.push(output.to_string());
+        }
+    }
+}
+
+/// A test executable built by cargo, run from its package folder
+struct TestBinary {
+    package: String,
+    executable: PathBuf,
+    folder: PathBuf,
+}
+
+/// Test executables of a build and the packages that failed to compile
+struct Build {
+    binaries: Vec<TestBinary>,
+    failed: HashSet<String>,
+}
+
+/// Builds the tests of the days with a single cargo invocation, returning the
+/// executables to run
+///
+/// The build keeps going past days that fail to compile, which are found from
+/// the errors of the compiler. Its messages are printed as cargo would.
+///
+/// # Errors
+///
+/// If cargo fails without any package failing to compile
+fn build(workspace: &Workspace, days: &[(u64, String)]) -> anyhow::Result<Build> {
+    let packages: HashMap<&PackageId, &cargo_metadata::Package> = workspace
+        .metadata()
+        .workspace_packages()
+        .into_iter()
+        .map(|package| (&package.id, package))
+        .collect();
+    let mut cargo = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args([
+            "build",
+            "--tests",
+            "--keep-going",
+            "--message-format=json-diagnostic-rendered-ansi",
+        ])
+        .args(days.iter().flat_map(|(_, package)| ["-p", package]))
+        .current_dir(workspace.root())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    let mut binaries = Vec::new();
+    let mut failed = HashSet::new();
+    let stdout = cargo.stdout.take().expect("stdout is piped");
+    for message in Message::parse_stream(BufReader::new(stdout)) {
+        let artifact = match message? {
+            Message::CompilerArtifact(artifact) => artifact,
+            Message::CompilerMessage(message) => {
+                if let Some(rendered) = &message.message.rendered {
+                    eprint!("{rendered}");
+                }
+                let error = matches!(
+                    message.message.level,
+                    DiagnosticLevel::Error | DiagnosticLevel::Ice
+                );
+                if let (true, Some(package)) = (error, packages.get(&message.package_id)) {
+                    failed.insert(package.name.to_string());
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        let (Some(executable), Some(package)) =
+            (artifact.executable, packages.get(&artifact.package_id))
+        else {
+            continue;
+        };
+        if artifact.profile.test {
+            binaries.push(TestBinary {
+                package: package.name.to_string(),
+                executable: executable.into_std_path_buf(),
+                folder: package.manifest_path.parent().map_or_else(
+                    || workspace.root().to_path_buf(),
+                    |p| p.as_std_path().to_path_buf(),
+                ),
+            });
+        }
+    }
+    let status = cargo.wait()?;
+    if !status.success() && failed.is_empty() {
+        bail!("cargo build failed with {status}");
+    }
+    Ok(Build { binaries, failed })
+}
+
+fn print_summary(results: &[(String, DayResult)]) {
+    const HEADER: [&str; 5] = ["Day", "Passed", "Failed", "Ignored", "Result"];
+    let rows: Vec<[String; 5]> = results
+        .iter()
+        .map(|(package, result)| {
+            [
+                package.clone(),
+                result.passed.to_string(),
+                result.failed.to_string(),
+                result.ignored.to_string(),
+                if !result.built {
+                    "build failed".to_string()
+                } else if result.ok() {
+                    "ok".to_string()
+                } else {
+                    "FAILED".to_string()
+                },
+            ]
+        })
+        .collect();
+    print_table(HEADER, &rows);
+}
+
+/// Builds and runs the tests of the days in parallel, keeping going past
+/// failures
+pub fn run_tests(
+    workspace: &Workspace,
+    days: &[(u64, String)],
+) -> anyhow::Result<Vec<(String, DayResult)>> {
+    let Build { binaries, failed } = build(workspace, days)?;
+
+    let outputs: Vec<(String, anyhow::Result<(String, bool)>)> = thread::scope(|scope| {
+        let runs: Vec<_> = binaries
+            .iter()
+            .map(|binary| {
+                scope.spawn(|| {
+                    let output = Command::new(&binary.executable)
+                        .current_dir(&binary.folder)
+                        .output()?;
+                    let text = String::from_utf8_lossy(&output.stdout).into_owned()
+                        + &String::from_utf8_lossy(&output.stderr);
+                    Ok((text, output.status.success()))
+                })
+            })
+            .collect();
+        binaries
+            .iter()
+            .zip(runs)
+            .map(|(binary, run)| {
+                let output = run.join().expect("test runner thread panicked");
+                (binary.package.clone(), output)
+            })
+            .collect()
+    });
+
+    let mut results: Vec<(String, DayResult)> = days
+        .iter()
+        .map(|(_, package)| {
+            let result = DayResult {
+                built: !failed.contains(package),
+                ..DayResult::default()
+            };
+            (package.clone(), result)
+        })
+        .collect();
+    for (package, output) in outputs {
+        let Some((_, result)) = results.iter_mut().find(|(name, _)| *name == package) else {
+            continue;
+        };
+        match output {
+            Ok((text, success)) => result.add_output(&text, success),
+            Err(error) => result
+                .output
+                .push(format!("Could not run the tests: {error}")),
+        }
+    }
+
+    Ok(results)
+}
+
+/// Tests all of the days of the year in parallel, reporting every failure
+/// instead of stopping at the first
+pub fn test_all(workspace: &Workspace) -> anyhow::Result<()> {
+    let days = workspace.days();
+    if days.is_empty() {
+        bail!("No day to test for {}", workspace.year());
+    }
+    let results = run_tests(workspace, &days)?;
+    print_summary(&results);
+    let failed: Vec<_> = results.iter().filter(|(_, result)| !result.ok()).collect();
+    for (package, result) in &failed {
+        println!("\n{package}");
+        if !result.built {
+            println!("  did not compile, see the errors above");
+        }
+        for name in &result.failures {
+            println!("  {name}");
+        }
+    }
+    for (package, result) in &failed {
+        for output in &result.output {
+            println!("\n---- {package} ----\n{}", output.trim_end());
+        }
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        bail!("{} of {} days failed", failed.len(), results.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_output() {
+        let mut result = DayResult::default();
+        result.add_output(
+            "running 3 tests
+test tests::test_parser ... ok
+test tests::test_part2 ... FAILED
+test tests::test_part1 ... ignored
+
+failures:
+
+---- tests::test_part2 stdout ----
+assertion failed
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s
+",
+            false,
+        );
+        assert_eq!((result.passed, result.failed, result.ignored), (1, 1, 1));
+        assert_eq!(result.failures, ["tests::test_part2"]);
+        assert!(!result.ok());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::workspace::Workspace;
+use log::{debug, warn};
+use notify_debouncer_full::{new_debouncer, notify::RecursiveMode, DebouncedEvent};
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+use xshell::{cmd, Shell};
+
+/// How long the files have to stay unchanged before running again, so an
+/// editor saving several files triggers a single run
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// What `xtask watch` runs on every change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Test,
+    Run,
+}
+
+/// Determines if a changed file is one of the watched ones: a source file of
+/// the day or of `utils`, or the input
+fn is_watched(path: &Path, sources: &[PathBuf], input: &Path) -> bool {
+    path == input || sources.iter().any(|folder| path.starts_with(folder))
+}
+
+/// Determines if an event changes a watched file, as opposed to reading it
+/// like every build does
+fn is_change(event: &DebouncedEvent, sources: &[PathBuf], input: &Path) -> bool {
+    !event.kind.is_access()
+        && event
+            .paths
+            .iter()
+            .any(|path| is_watched(path, sources, input))
+}
+
+/// Clears the screen and runs the tests or the solution of the day once,
+/// ending with a banner telling whether it passed
+fn run_once(sh: &Shell, workspace: &Workspace, day: u64, action: Action) {
+    print!("\x1b[2J\x1b[H");
+    let package = workspace.package(day);
+    let input = workspace.input(day);
+    let start = Instant::now();
+    let result = match action {
+        Action::Test => cmd!(sh, "cargo test -q --package {package}").run(),
+        Action::Run => cmd!(
+            sh,
+            "cargo run -q --release --package {package} -- {input} -p both"
+        )
+        .run(),
+    };
+    let what = match action {
+        Action::Test => "tests",
+        Action::Run => "solution",
+    };
+    let elapsed = start.elapsed();
+    match result {
+        Ok(()) => println!("\n\x1b[1;42m PASS \x1b[0m {package} {what} in {elapsed:.2?}"),
+        Err(error) => {
+            debug!("{error}");
+            println!("\n\x1b[1;41m FAIL \x1b[0m {package} {what} in {elapsed:.2?}");
+        }
+    }
+    println!("Watching for changes, Ctrl-C to stop");
+}
+
+/// Runs the tests or the solution of a day every time its sources, the
+/// sources of `utils` or its input change
+pub fn watch(sh: &Shell, workspace: &Workspace, day: u64, action: Action) -> anyhow::Result<()> {
+    let folder = workspace.day_folder(day);
+    let sources = [
+        folder.join("src"),
+        workspace.root().join("utils").join("src"),
+    ];
+    let input = workspace.input(day);
+
+    let (sender, receiver) = mpsc::channel();
+    let mut debouncer = new_debouncer(DEBOUNCE, None, sender)?;
+    for source in &sources {
+        debouncer.watch(source, RecursiveMode::Recursive)?;
+    }
+    // Watching the folder rather than the file keeps up with editors that
+    // replace the input instead of writing to it
+    debouncer.watch(&folder, RecursiveMode::NonRecursive)?;
+
+    run_once(sh, workspace, day, action);
+    for events in receiver {
+        match events {
+            Ok(events) => {
+                if events
+                    .iter()
+                    .any(|event| is_change(event, &sources, &input))
+                {
+                    run_once(sh, workspace, day, action);
+                }
+            }
+            Err(errors) => {
+                for error in errors {
+                    warn!("Watch error: {error}");
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_watched() {
+        let sources = [
+            PathBuf::from("/aoc/day-06/src"),
+            PathBuf::from("/aoc/utils/src"),
+        ];
+        let input = Path::new("/aoc/day-06/input.txt");
+        assert!(is_watched(
+            Path::new("/aoc/day-06/src/lib.rs"),
+            &sources,
+            input
+        ));
+        assert!(is_watched(
+            Path::new("/aoc/utils/src/grid.rs"),
+            &sources,
+            input
+        ));
+        assert!(is_watched(input, &sources, input));
+        assert!(!is_watched(
+            Path::new("/aoc/day-06/answers.toml"),
+            &sources,
+            input
+        ));
+        assert!(!is_watched(
+            Path::new("/aoc/day-07/src/lib.rs"),
+            &sources,
+            input
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::Context;
+use cargo_metadata::{Metadata, MetadataCommand};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// How the days of the workspace are laid out
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Layout {
+    /// Days of the configured year are in `day-XX`, other years in
+    /// `YYYY/day-XX`
+    #[default]
+    Flat,
+    /// Days of every year are in `YYYY/day-XX`
+    Year,
+}
+
+/// Settings in `[workspace.metadata.aoc]` of the root `Cargo.toml`
+///
+/// ```toml
+/// [workspace.metadata.aoc]
+/// year = 2024
+/// layout = "flat"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+struct AocConfig {
+    year: Option<u16>,
+    #[serde(default)]
+    layout: Layout,
+}
+
+/// The workspace, and the event and account the tasks work on
+///
+/// Every path and package name of a day is worked out here, so the tasks
+/// agree on where a day lives.
+#[derive(Debug)]
+pub struct Workspace {
+    metadata: Metadata,
+    year: u16,
+    namespaced: bool,
+    profile: Option<String>,
+}
+
+impl Workspace {
+    /// Reads the workspace metadata and picks the event, which is `year` when
+    /// given and the year of the workspace config otherwise
+    ///
+    /// `profile` is the session key profile to use with the website, see
+    /// [`read_session`](crate::session::read_session).
+    pub fn load(year: Option<u16>, profile: Option<String>) -> anyhow::Result<Self> {
+        let metadata = MetadataCommand::new().no_deps().exec()?;
+        let config: AocConfig = match metadata.workspace_metadata.get("aoc") {
+            Some(config) => serde_json::from_value(config.clone())
+                .context("Invalid [workspace.metadata.aoc] in Cargo.toml")?,
+            None => AocConfig::default(),
+        };
+        let year = year.or(config.year).context(
+            "No year to work on, use --year, AOC_YEAR or year in [workspace.metadata.aoc]",
+        )?;
+        Ok(Self {
+            namespaced: config.layout == Layout::Year || config.year != Some(year),
+            metadata,
+            year,
+            profile,
+        })
+    }
+
+    pub const fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    pub fn root(&self) -> &Path {
+        self.metadata.workspace_root.as_std_path()
+    }
+
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Session key profile, `None` for the default one
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Determines if the days of the year are in a `YYYY` folder
+    pub const fn namespaced(&self) -> bool {
+        self.namespaced
+    }
+
+    /// Name of the package of a day
+    pub fn package(&self, day: u64) -> String {
+        if self.namespaced {
+            format!("day-{}-{day:0>2}", self.year)
+        } else {
+            format!("day-{day:0>2}")
+        }
+    }
+
+    /// Folder holding the package of a day
+    pub fn day_folder(&self, day: u64) -> PathBuf {
+        let folder = format!("day-{day:0>2}");
+        if self.namespaced {
+            self.root().join(self.year.to_string()).join(folder)
+        } else {
+            self.root().join(folder)
+        }
+    }
+
+    /// Puzzle input of a day
+    pub fn input(&self, day: u64) -> PathBuf {
+        self.day_folder(day).join("input.txt")
+    }
+
+    /// Days of the year that have a package, with the package name
+    pub fn days(&self) -> Vec<(u64, String)> {
+        (1..=25)
+            .map(|day| (day, self.package(day)))
+            .filter(|(_, package)| {
+                self.metadata
+                    .workspace_packages()
+                    .iter()
+                    .any(|p| p.name == *package)
+            })
+            .collect()
+    }
+}